@@ -1,3 +1,5 @@
+use lib::error::LibResult;
+
 fn do_split(s: &str) -> [usize; 3] {
     let t = s.split('x').map(|n| n.parse().unwrap()).collect::<Vec<_>>();
 
@@ -33,9 +35,9 @@ fn part2(presents: &mut [[usize; 3]]) {
 }
 
 fn main() -> anyhow::Result<()> {
-    let mut presents: Vec<_> = lib::input_lines("input/day2.txt")
-        .map(|line| do_split(&line))
-        .collect::<Vec<_>>();
+    let mut presents: Vec<_> = lib::input_lines("input/day2.txt")?
+        .map(|line| line.map(|line| do_split(&line)))
+        .collect::<LibResult<Vec<_>>>()?;
 
     part1(&presents);
     part2(&mut presents);
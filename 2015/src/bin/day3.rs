@@ -1,9 +1,9 @@
-use std::{collections::HashMap, iter::once};
+use std::iter::once;
 
-use lib::{direction::Direction, error::LibResult, nposition::NPosition};
+use lib::{direction::Direction, error::LibResult, grid::SparseGrid, nposition::NPosition};
 
 fn part1(directions: &[Direction]) {
-    let visited_houses: HashMap<NPosition, usize> = once(NPosition::new(0, 0))
+    let visited_houses: SparseGrid<usize> = once(NPosition::new(0, 0))
         .chain(
             directions
                 .iter()
@@ -11,37 +11,36 @@ fn part1(directions: &[Direction]) {
                     position.apply_direction(direction).map(|_| *position).ok()
                 }),
         )
-        .fold(HashMap::new(), |mut map, position| {
-            *map.entry(position).or_default() += 1;
+        .fold(SparseGrid::new(), |mut map, position| {
+            *map.get_or_insert_with(position, || 0) += 1;
             map
         });
 
-    let houses_visited = visited_houses.values().count();
+    let houses_visited = visited_houses.len();
 
     println!("Thanks to Santa, {houses_visited} houses received at least one present.");
 }
 
 fn part2(directions: &[Direction]) {
-    let visited_houses: HashMap<NPosition, usize> =
-        once((NPosition::new(0, 0), NPosition::new(0, 0)))
-            .chain(directions.chunks_exact(2).scan(
-                (NPosition::new(0, 0), NPosition::new(0, 0)),
-                |(santa, robot), directions| {
-                    santa
-                        .apply_direction(&directions[0])
-                        .map(|_| *santa)
-                        .ok()
-                        .zip(robot.apply_direction(&directions[1]).map(|_| *robot).ok())
-                },
-            ))
-            .fold(HashMap::new(), |mut map, (santa, robot)| {
-                *map.entry(santa).or_default() += 1;
-                *map.entry(robot).or_default() += 1;
-
-                map
-            });
-
-    let houses_visited = visited_houses.values().count();
+    let visited_houses: SparseGrid<usize> = once((NPosition::new(0, 0), NPosition::new(0, 0)))
+        .chain(directions.chunks_exact(2).scan(
+            (NPosition::new(0, 0), NPosition::new(0, 0)),
+            |(santa, robot), directions| {
+                santa
+                    .apply_direction(&directions[0])
+                    .map(|_| *santa)
+                    .ok()
+                    .zip(robot.apply_direction(&directions[1]).map(|_| *robot).ok())
+            },
+        ))
+        .fold(SparseGrid::new(), |mut map, (santa, robot)| {
+            *map.get_or_insert_with(santa, || 0) += 1;
+            *map.get_or_insert_with(robot, || 0) += 1;
+
+            map
+        });
+
+    let houses_visited = visited_houses.len();
 
     println!(
         "Thanks to Santa and Robo-Santa, {houses_visited} houses received at least one present."
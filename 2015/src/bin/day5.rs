@@ -1,3 +1,5 @@
+use lib::error::LibResult;
+
 fn part1(line: &str) -> bool {
     line.chars()
         .filter(|c| ['a', 'e', 'i', 'o', 'u'].contains(c))
@@ -29,7 +31,7 @@ fn solve(lines: &[String], filter: fn(&str) -> bool) {
 }
 
 fn main() -> anyhow::Result<()> {
-    let lines = lib::input_lines("input/day5.txt").collect::<Vec<_>>();
+    let lines = lib::input_lines("input/day5.txt")?.collect::<LibResult<Vec<_>>>()?;
 
     solve(&lines, part1);
     solve(&lines, part2);
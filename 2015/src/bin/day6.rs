@@ -1,6 +1,10 @@
 use std::str::FromStr;
 
-use lib::{grid::Grid, position::Position};
+use lib::{
+    grid::{BitGrid, Grid},
+    pattern,
+    position::Position,
+};
 
 #[derive(Debug)]
 enum Instruction {
@@ -9,19 +13,6 @@ enum Instruction {
     TurnOff,
 }
 
-impl FromStr for Instruction {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "toggle" => Ok(Self::Toggle),
-            "turn on" => Ok(Self::TurnOn),
-            "turn off" => Ok(Self::TurnOff),
-            _ => Err(anyhow::anyhow!("{s} isn't a valid instruction")),
-        }
-    }
-}
-
 #[derive(Debug)]
 struct InstrAndPos {
     instruction: Instruction,
@@ -33,40 +24,35 @@ impl FromStr for InstrAndPos {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, bottom_right) = s
-            .split_once(" through ")
-            .ok_or_else(|| anyhow::anyhow!("{s}"))?;
-
-        let (start, top_left) = start
-            .rsplit_once(' ')
-            .ok_or_else(|| anyhow::anyhow!("{start}"))?;
-
-        let instruction = start.parse()?;
-        let (top_left1, top_left2) = top_left.split_once(',').unwrap();
-        let (bottom_right1, bottom_right2) = bottom_right.split_once(',').unwrap();
+        let (instruction, [x1, y1, x2, y2]) = [
+            (Instruction::Toggle, "toggle {},{} through {},{}"),
+            (Instruction::TurnOn, "turn on {},{} through {},{}"),
+            (Instruction::TurnOff, "turn off {},{} through {},{}"),
+        ]
+        .into_iter()
+        .find_map(|(instruction, template)| Some((instruction, pattern::scan(template, s).ok()?)))
+        .ok_or_else(|| anyhow::anyhow!("{s} isn't a valid instruction"))?;
 
         Ok(Self {
             instruction,
-            top_left: Position::new(top_left1.parse()?, top_left2.parse()?),
-            bottom_right: Position::new(bottom_right1.parse()?, bottom_right2.parse()?),
+            top_left: Position::new(x1, y1),
+            bottom_right: Position::new(x2, y2),
         })
     }
 }
 
 fn part1(instr_and_pos: &[InstrAndPos]) -> anyhow::Result<()> {
-    let mut grid = Grid::try_from((vec![false; 1000 * 1000], 1000))?;
+    let mut grid = BitGrid::new(1000, 1000);
 
     for instr in instr_and_pos {
-        let func = match instr.instruction {
-            Instruction::Toggle => |b: &mut bool| *b = !*b,
-            Instruction::TurnOn => |b: &mut bool| *b = true,
-            Instruction::TurnOff => |b: &mut bool| *b = false,
-        };
-        grid.iter_rect_mut(instr.top_left, instr.bottom_right)?
-            .for_each(func);
+        match instr.instruction {
+            Instruction::Toggle => grid.toggle_rect(instr.top_left, instr.bottom_right)?,
+            Instruction::TurnOn => grid.set_rect(instr.top_left, instr.bottom_right)?,
+            Instruction::TurnOff => grid.clear_rect(instr.top_left, instr.bottom_right)?,
+        }
     }
 
-    let lit_lights = grid.iter().filter(|&b| *b).count();
+    let lit_lights = grid.count_ones();
 
     println!("{lit_lights} are lit.");
 
@@ -1,3 +1,5 @@
+use lib::error::LibResult;
+
 fn memory_size_1(string: &str) -> (usize, usize) {
     let mut chars = string[1..string.len() - 1].chars();
     let (mut nb_chars, mut mem_size) = (2, 0);
@@ -65,7 +67,7 @@ fn solve(lines: &[String], f: fn(&str) -> (usize, usize)) {
 }
 
 fn main() -> anyhow::Result<()> {
-    let lines = lib::input_lines("input/day8.txt").collect::<Vec<_>>();
+    let lines = lib::input_lines("input/day8.txt")?.collect::<LibResult<Vec<_>>>()?;
 
     solve(&lines, memory_size_1);
     solve(&lines, memory_size_2);
@@ -3,7 +3,7 @@ use std::{
     str::FromStr,
 };
 
-use lib::permutation::Permutations;
+use lib::{pattern, permutation::Permutations};
 
 #[derive(Debug)]
 struct CityToCityDistance(String, String, usize);
@@ -12,14 +12,9 @@ impl FromStr for CityToCityDistance {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (cities, distance) = s.split_once(" = ").unwrap();
-        let (from, to) = cities.split_once(" to ").unwrap();
-
-        Ok(CityToCityDistance(
-            from.into(),
-            to.into(),
-            distance.parse()?,
-        ))
+        let (from, to, distance) = pattern::scan("{} to {} = {}", s)?;
+
+        Ok(CityToCityDistance(from, to, distance))
     }
 }
 
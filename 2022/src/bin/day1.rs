@@ -10,16 +10,14 @@ fn part2(mut inventory_lines: Vec<u32>) {
 }
 
 fn main() -> anyhow::Result<()> {
-    let inventory_lines: Vec<_> = lib::input_lines("input/day1.txt")
-        .collect::<Vec<_>>()
-        .split(|inventory_line| inventory_line.is_empty())
-        .map(|elf_inventory_lines| {
-            elf_inventory_lines
-                .iter()
-                .map(|elf_inventory_line| elf_inventory_line.parse::<u32>().unwrap())
-                .sum::<u32>()
+    let inventory_lines = lib::input_sections("input/day1.txt")?
+        .iter()
+        .map(|elf_inventory| {
+            elf_inventory
+                .parse_lines::<u32>()
+                .map(|calories| calories.iter().sum())
         })
-        .collect();
+        .collect::<Result<Vec<u32>, _>>()?;
     part1(&inventory_lines[..]);
     part2(inventory_lines);
     Ok(())
@@ -69,7 +69,7 @@ impl Cpu {
         } else {
             print!(".");
         }
-        if (self.cycle) % 40 == 0 {
+        if current_pixel == 39 {
             println!();
         }
     }
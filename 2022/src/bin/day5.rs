@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use lib::{error::LibResult, pattern};
+
 #[derive(Debug)]
 struct Instruction {
     nb: usize,
@@ -11,16 +13,7 @@ impl FromStr for Instruction {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut words = s.split(' ');
-        // discard 'move'
-        words.next();
-        let nb = words.next().unwrap().parse()?;
-        // discard 'from'
-        words.next();
-        let from = words.next().unwrap().parse()?;
-        // discord 'to'
-        words.next();
-        let to = words.next().unwrap().parse()?;
+        let (nb, from, to) = pattern::scan("move {} from {} to {}", s)?;
 
         Ok(Self { nb, from, to })
     }
@@ -77,8 +70,8 @@ fn part2(crates: &Crates) {
     );
 }
 
-fn main() {
-    let lines = lib::input_lines("input/day5.txt").collect::<Vec<_>>();
+fn main() -> anyhow::Result<()> {
+    let lines = lib::input_lines("input/day5.txt")?.collect::<LibResult<Vec<_>>>()?;
     let stacks_def: Vec<Vec<char>> = lines
         .iter()
         .take_while(|s| !s.starts_with(" 1"))
@@ -107,8 +100,8 @@ fn main() {
         .iter()
         .skip_while(|s| !s.is_empty())
         .skip(1)
-        .map(|s| s.parse::<Instruction>().unwrap())
-        .collect();
+        .map(|s| s.parse::<Instruction>())
+        .collect::<anyhow::Result<_>>()?;
 
     let crates = Crates {
         stacks,
@@ -117,4 +110,6 @@ fn main() {
 
     part1(&crates);
     part2(&crates);
+
+    Ok(())
 }
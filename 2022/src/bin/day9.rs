@@ -1,103 +1,49 @@
-use std::{collections::HashSet, ops::RangeInclusive, str::FromStr};
+use std::{collections::HashSet, str::FromStr};
 
-#[derive(Debug)]
-enum Direction {
-    U,
-    D,
-    L,
-    R,
-}
+use lib::{direction::Direction8, nposition::NPosition};
 
 #[derive(Debug)]
 struct Motion {
-    direction: Direction,
-    len: isize,
+    direction: Direction8,
+    len: usize,
 }
 
 impl FromStr for Motion {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let motion = match s.split_once(' ') {
-            Some(("U", len)) => Self {
-                direction: Direction::U,
-                len: len.parse()?,
-            },
-            Some(("D", len)) => Self {
-                direction: Direction::D,
-                len: len.parse()?,
-            },
-            Some(("L", len)) => Self {
-                direction: Direction::L,
-                len: len.parse()?,
-            },
-            Some(("R", len)) => Self {
-                direction: Direction::R,
-                len: len.parse()?,
-            },
-            _ => Err(anyhow::anyhow!("{s} is invalid"))?,
-        };
-
-        Ok(motion)
+        let (direction, len) = s
+            .split_once(' ')
+            .ok_or_else(|| anyhow::anyhow!("{s} is invalid"))?;
+
+        Ok(Self {
+            direction: direction.parse()?,
+            len: len.parse()?,
+        })
     }
 }
 
-#[derive(Default, PartialEq, Eq, Hash, Copy, Clone, Debug)]
-struct Position(isize, isize);
-
-const RANGE: RangeInclusive<isize> = -1..=1;
-
-impl Position {
-    fn is_adjacent_to(&self, other: Self) -> bool {
-        RANGE.contains(&(self.0 - other.0)) && RANGE.contains(&(self.1 - other.1))
-    }
-
-    fn go_direction(&mut self, direction: &Direction) {
-        match direction {
-            Direction::U => self.1 += 1,
-            Direction::D => self.1 -= 1,
-            Direction::L => self.0 -= 1,
-            Direction::R => self.0 += 1,
-        }
-    }
-
-    fn join(&mut self, to_join: Self) {
-        if !self.is_adjacent_to(to_join) {
-            match self.0.cmp(&to_join.0) {
-                std::cmp::Ordering::Less => self.0 += 1,
-                std::cmp::Ordering::Equal => (),
-                std::cmp::Ordering::Greater => self.0 -= 1,
-            }
-            match self.1.cmp(&to_join.1) {
-                std::cmp::Ordering::Less => self.1 += 1,
-                std::cmp::Ordering::Equal => (),
-                std::cmp::Ordering::Greater => self.1 -= 1,
-            }
-        }
-    }
-}
-
-struct Rope<const SIZE: usize>([Position; SIZE]);
+struct Rope<const SIZE: usize>([NPosition; SIZE]);
 
 impl<const SIZE: usize> Rope<SIZE> {
     fn new() -> Self {
-        Self([Position::default(); SIZE])
-    }
-
-    fn get_head(&mut self) -> &mut Position {
-        &mut self.0[0]
+        Self([NPosition::default(); SIZE])
     }
 
-    fn get_tail(&self) -> Position {
+    fn get_tail(&self) -> NPosition {
         self.0[SIZE - 1]
     }
 
-    fn do_motion(&mut self, motion: &Motion, tail_positions: &mut HashSet<Position>) {
+    fn do_motion(&mut self, motion: &Motion, tail_positions: &mut HashSet<NPosition>) {
+        let (dx, dy) = motion.direction.offset();
         for _ in 0..motion.len {
-            self.get_head().go_direction(&motion.direction);
+            self.0[0] += NPosition::new(dx, dy);
 
-            for i in 0..SIZE - 1 {
-                self.0[i + 1].join(self.0[i]);
+            for i in 1..SIZE {
+                let (knot, to_join) = (self.0[i], self.0[i - 1]);
+                if knot.chebyshev(&to_join) > 1 {
+                    self.0[i] = knot.step_toward(&to_join);
+                }
             }
             tail_positions.insert(self.get_tail());
         }
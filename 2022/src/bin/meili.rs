@@ -1,17 +1,14 @@
 use std::{ops::Add, str::FromStr};
 
-use lib::btree::{BTree, Key};
-
-#[derive(Debug)]
-pub enum Direction {
-    L,
-    R,
-}
+use lib::{
+    btree::{BTree, Key},
+    direction::Turn,
+};
 
 #[derive(Debug)]
 struct House {
     kid: String,
-    path: Vec<Direction>,
+    path: Vec<Turn>,
 }
 
 impl FromStr for House {
@@ -22,11 +19,7 @@ impl FromStr for House {
             .map(|(kid, house)| {
                 let path = house
                     .chars()
-                    .map(|char| match char {
-                        'R' => Ok(Direction::R),
-                        'L' => Ok(Direction::L),
-                        _ => Err(anyhow::anyhow!("Got {char} in house path")),
-                    })
+                    .map(Turn::try_from)
                     .collect::<Result<Vec<_>, _>>()?;
 
                 Ok(House {
@@ -44,10 +37,10 @@ impl Add<House> for BTree<Option<String>> {
     fn add(mut self, house: House) -> Self::Output {
         let mut current = self.get_root().unwrap().get_key();
 
-        for direction in house.path {
-            current = match direction {
-                Direction::L => self.get_or_add_left(None, &current),
-                Direction::R => self.get_or_add_right(None, &current),
+        for turn in house.path {
+            current = match turn {
+                Turn::Left => self.get_or_add_left(None, &current),
+                Turn::Right => self.get_or_add_right(None, &current),
             }
         }
 
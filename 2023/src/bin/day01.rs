@@ -1,3 +1,5 @@
+use lib::error::LibResult;
+
 fn solve<'a>(
     part: usize,
     instructions: impl Iterator<Item = &'a String>,
@@ -13,7 +15,7 @@ fn solve<'a>(
                 .map(|c| c.to_digit(10).unwrap())
                 .ok_or_else(|| anyhow::anyhow!("Line {index} - {ins} has no digit"))?;
             let second_digit = digits
-                .last()
+                .next_back()
                 .map(|c| c.to_digit(10).unwrap())
                 .unwrap_or(first_digit);
 
@@ -29,7 +31,7 @@ fn solve<'a>(
 }
 
 fn main() -> anyhow::Result<()> {
-    let lines = lib::input_lines("input/day01.txt").collect::<Vec<_>>();
+    let lines = lib::input_lines("input/day01.txt")?.collect::<LibResult<Vec<_>>>()?;
 
     solve(1, lines.iter(), str::to_string)?;
     solve(2, lines.iter(), |s| {
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use lib::pattern;
+
 #[derive(Debug, Default)]
 struct Draw {
     green: usize,
@@ -13,14 +15,12 @@ impl FromStr for Draw {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         s.split(", ")
             .try_fold(Draw::default(), |mut draw_acc, draw_part| {
-                match draw_part.split_once(' ') {
-                    Some((number, color)) => match color {
-                        "red" => draw_acc.red = number.parse()?,
-                        "blue" => draw_acc.blue = number.parse()?,
-                        "green" => draw_acc.green = number.parse()?,
-                        unknown => anyhow::bail!("Unknown color - {unknown}"),
-                    },
-                    None => anyhow::bail!("No space in {s:?}"),
+                let (number, color): (usize, String) = pattern::scan("{} {}", draw_part)?;
+                match color.as_str() {
+                    "red" => draw_acc.red = number,
+                    "blue" => draw_acc.blue = number,
+                    "green" => draw_acc.green = number,
+                    unknown => anyhow::bail!("Unknown color - {unknown}"),
                 }
                 Ok(draw_acc)
             })
@@ -37,14 +37,7 @@ impl FromStr for Game {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s
-            .strip_prefix("Game ")
-            .ok_or_else(|| anyhow::anyhow!("Not starting with 'Game ' - {s}"))?;
-        let (id_str, draws_str) = s
-            .split_once(": ")
-            .ok_or_else(|| anyhow::anyhow!("No ': ' - {s}"))?;
-
-        let id = id_str.parse()?;
+        let (id, draws_str): (usize, String) = pattern::scan("Game {}: {}", s)?;
 
         let draws = draws_str
             .split("; ")
@@ -98,8 +91,8 @@ fn part2(games: Vec<Game>) {
 }
 
 fn main() -> anyhow::Result<()> {
-    let games: Vec<Game> = lib::input_lines("./input/day02.txt")
-        .map(|s| s.parse())
+    let games: Vec<Game> = lib::input_lines("./input/day02.txt")?
+        .map(|s| s?.parse())
         .collect::<anyhow::Result<Vec<_>>>()?;
 
     part1(&games);
@@ -1,120 +1,86 @@
 use std::collections::HashSet;
 
-fn part1(grid: &[Vec<char>]) {
+use lib::{grid::Grid, position::Position};
+
+/// Retrieves the position of the first digit of the number containing `pos`.
+fn number_start(grid: &Grid<char>, mut pos: Position) -> Position {
+    while pos.x() > 0
+        && grid
+            .get(&Position::new(pos.x() - 1, pos.y()))
+            .is_ok_and(char::is_ascii_digit)
+    {
+        pos = Position::new(pos.x() - 1, pos.y());
+    }
+    pos
+}
+
+/// Reads the number whose first digit is at `pos`.
+fn number_at(grid: &Grid<char>, pos: &Position) -> anyhow::Result<usize> {
+    Ok(grid
+        .iter_row(pos.y())?
+        .skip(pos.x())
+        .map_while(|c| c.to_digit(10))
+        .fold(0, |acc, digit| acc * 10 + digit as usize))
+}
+
+/// Retrieves the positions of the first digit of every number adjacent to `pos`.
+fn adjacent_numbers(grid: &Grid<char>, pos: &Position) -> anyhow::Result<HashSet<Position>> {
+    Ok(grid
+        .neighbors8(pos)?
+        .filter(|(_, c)| c.is_ascii_digit())
+        .map(|(pos, _)| number_start(grid, pos))
+        .collect())
+}
+
+fn part1(grid: &Grid<char>) -> anyhow::Result<()> {
     let mut set = HashSet::new();
-    // Iterate over each char.
-    for row in 0..grid.len() {
-        for (col, c) in grid[row].iter().enumerate() {
-            // Dodge every char that isn't a symbol.
-            if c.is_ascii_digit() || *c == '.' {
-                continue;
-            }
-
-            // Cursed code to iterate over cells around the symbol.
-            let (coli, rowi) = (col as isize, row as isize);
-            for cand_row in [rowi - 1, rowi, rowi + 1] {
-                for cand_col in [coli - 1, coli, coli + 1] {
-                    if cand_row < 0
-                        || cand_row >= (grid.len() as isize)
-                        || cand_col < 0
-                        || cand_col >= (grid[row].len() as isize)
-                        || !grid[cand_row as usize][cand_col as usize].is_ascii_digit()
-                    {
-                        continue;
-                    }
-
-                    // Get the position of the first digit of the number.
-                    let mut colii = cand_col;
-                    while colii > 0
-                        && grid[cand_row as usize][(colii - 1) as usize].is_ascii_digit()
-                    {
-                        colii -= 1;
-                    }
-                    set.insert((cand_row as usize, colii as usize));
-                }
-            }
-        }
+    // Dodge every char that isn't a symbol.
+    for (pos, _) in grid
+        .enumerate()
+        .filter(|(_, c)| !c.is_ascii_digit() && **c != '.')
+    {
+        set.extend(adjacent_numbers(grid, &pos)?);
     }
 
     // Sum all the numbers.
     let sum = set
         .iter()
-        .map(|(row, mut col)| {
-            let mut acc = 0;
-            while col < grid[*row].len() && grid[*row][col].is_ascii_digit() {
-                acc = acc * 10 + (grid[*row][col].to_digit(10).unwrap() as usize);
-                col += 1;
-            }
-            acc
-        })
-        .sum::<usize>();
+        .map(|pos| number_at(grid, pos))
+        .sum::<anyhow::Result<usize>>()?;
 
     println!("The sum of all of the part numbers in the engine schematic is {sum}");
+
+    Ok(())
 }
 
-fn part2(grid: &[Vec<char>]) {
+fn part2(grid: &Grid<char>) -> anyhow::Result<()> {
     let mut sum = 0;
-    for row in 0..grid.len() {
-        for (col, c) in grid[row].iter().enumerate() {
-            // Only interested in '*'
-            if *c != '*' {
-                continue;
-            }
-
-            let mut set = HashSet::new();
-
-            // Iterate over all possible cells around '*'.
-            let (coli, rowi) = (col as isize, row as isize);
-            for cand_row in [rowi - 1, rowi, rowi + 1] {
-                for cand_col in [coli - 1, coli, coli + 1] {
-                    if cand_row < 0
-                        || cand_row >= (grid.len() as isize)
-                        || cand_col < 0
-                        || cand_col >= (grid[row].len() as isize)
-                        || !grid[cand_row as usize][cand_col as usize].is_ascii_digit()
-                    {
-                        continue;
-                    }
-
-                    // Register the positions of the first digit of every numbers.
-                    let mut colii = cand_col;
-                    while colii > 0
-                        && grid[cand_row as usize][(colii - 1) as usize].is_ascii_digit()
-                    {
-                        colii -= 1;
-                    }
-                    set.insert((cand_row as usize, colii as usize));
-                }
-            }
-
-            // If there isn't exactly 2 numbers, we skip.
-            if set.len() != 2 {
-                continue;
-            }
-
-            // Add the product of those numbers to the total.
-            sum += set
-                .iter()
-                .map(|(row, mut col)| {
-                    let mut acc = 0;
-                    while col < grid[*row].len() && grid[*row][col].is_ascii_digit() {
-                        acc = acc * 10 + (grid[*row][col].to_digit(10).unwrap() as usize);
-                        col += 1;
-                    }
-                    acc
-                })
-                .product::<usize>();
+    // Only interested in '*'
+    for (pos, _) in grid.enumerate().filter(|(_, c)| **c == '*') {
+        let set = adjacent_numbers(grid, &pos)?;
+
+        // If there isn't exactly 2 numbers, we skip.
+        if set.len() != 2 {
+            continue;
         }
+
+        // Add the product of those numbers to the total.
+        sum += set
+            .iter()
+            .map(|pos| number_at(grid, pos))
+            .product::<anyhow::Result<usize>>()?;
     }
 
     println!("The sum of all of the gear ratios in your engine schematic is {sum}");
+
+    Ok(())
 }
 
-fn main() {
-    let grid = lib::input_lines("./input/day03.txt")
-        .map(|line| line.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+fn main() -> anyhow::Result<()> {
+    let grid: Grid<char> = lib::input_to_string("./input/day03.txt")?.parse()?;
+
+    part1(&grid)?;
+    part2(&grid)?;
 
-    part1(&grid);
-    part2(&grid);
+    Ok(())
 }
@@ -3,6 +3,8 @@ use std::{
     str::FromStr,
 };
 
+use lib::{error::LibResult, ints, pattern};
+
 struct Card {
     id: usize,
     winnings: HashSet<usize>,
@@ -13,29 +15,11 @@ impl FromStr for Card {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s
-            .strip_prefix("Card")
-            .map(str::trim_start)
-            .ok_or_else(|| anyhow::anyhow!("No prefix 'Card...'"))?;
-
-        let (id_str, numbers_str) = s
-            .split_once(": ")
-            .ok_or_else(|| anyhow::anyhow!("No ': '"))?;
-
-        let id = id_str.parse()?;
-
-        let (winnings_str, havings_str) = numbers_str
-            .split_once(" | ")
-            .ok_or_else(|| anyhow::anyhow!("No ' | '"))?;
+        let (id, winnings_str, havings_str): (usize, String, String) =
+            pattern::scan("Card {}: {} | {}", s)?;
 
-        let winnings = winnings_str
-            .split_whitespace()
-            .map(|w| w.parse().map_err(anyhow::Error::from))
-            .collect::<anyhow::Result<HashSet<_>>>()?;
-        let havings = havings_str
-            .split_whitespace()
-            .map(|w| w.parse().map_err(anyhow::Error::from))
-            .collect::<anyhow::Result<HashSet<_>>>()?;
+        let winnings = ints::uints(&winnings_str).collect::<LibResult<HashSet<_>>>()?;
+        let havings = ints::uints(&havings_str).collect::<LibResult<HashSet<_>>>()?;
 
         Ok(Card {
             id,
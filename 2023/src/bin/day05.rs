@@ -1,4 +1,6 @@
-use std::ops::Range;
+use std::{ops::Range, str::FromStr};
+
+use lib::{error::LibResult, ints, section::Section};
 
 #[derive(Debug)]
 struct Almanac {
@@ -12,39 +14,36 @@ struct MapRange {
     destination_range_start: usize,
 }
 
-fn parse_almanac(input: String) -> anyhow::Result<Almanac> {
-    let mut parts = input.split("\n\n");
+impl FromStr for MapRange {
+    type Err = anyhow::Error;
 
-    let seeds = parts
-        .next()
-        .and_then(|seed_line| seed_line.strip_prefix("seeds: "))
-        .map(|seed_numbers| {
-            seed_numbers
-                .split_whitespace()
-                .map(|n| n.parse().map_err(anyhow::Error::from))
-                .collect::<anyhow::Result<Vec<_>>>()
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [destination_range_start, source_range_start, range_size] = ints::uints_array(s)?;
+
+        Ok(MapRange {
+            source_range: source_range_start..source_range_start + range_size,
+            destination_range_start,
         })
-        .ok_or_else(|| anyhow::anyhow!("Can't parse seeds numbers"))??;
+    }
+}
 
-    let maps = parts
-        .map(|map_str| {
-            map_str
+fn parse_almanac(sections: Vec<Section>) -> anyhow::Result<Almanac> {
+    let mut sections = sections.into_iter();
+
+    let seeds = sections
+        .next()
+        .and_then(|seeds_section| {
+            seeds_section
                 .lines()
-                .skip(1)
-                .map(|line| {
-                    let mut range_parts = line.split_whitespace();
-                    let destination_range_start = range_parts.next().unwrap().parse().unwrap();
-                    let source_range_start: usize = range_parts.next().unwrap().parse().unwrap();
-                    let range_size: usize = range_parts.next().unwrap().parse().unwrap();
-
-                    MapRange {
-                        source_range: source_range_start..source_range_start + range_size,
-                        destination_range_start,
-                    }
-                })
-                .collect()
+                .first()
+                .and_then(|seed_line| seed_line.strip_prefix("seeds: "))
+                .map(|seed_numbers| ints::uints(seed_numbers).collect::<LibResult<Vec<_>>>())
         })
-        .collect();
+        .ok_or_else(|| anyhow::anyhow!("Can't parse seeds numbers"))??;
+
+    let maps = sections
+        .map(|section| section.parse_lines())
+        .collect::<anyhow::Result<Vec<_>>>()?;
 
     Ok(Almanac { seeds, maps })
 }
@@ -77,7 +76,7 @@ fn part2(almanac: Almanac) {
     let min_distance: usize = almanac
         .seeds
         .chunks_exact(2)
-        .flat_map(|chunk| (chunk[0]..chunk[0] + chunk[1]))
+        .flat_map(|chunk| chunk[0]..chunk[0] + chunk[1])
         .map(|seed| {
             almanac.maps.iter().fold(seed, |acc, map| {
                 match map
@@ -98,9 +97,9 @@ fn part2(almanac: Almanac) {
 }
 
 fn main() -> anyhow::Result<()> {
-    let input = lib::input_to_string("./input/day05.txt")?;
+    let sections = lib::input_sections("./input/day05.txt")?;
 
-    let almanac = parse_almanac(input)?;
+    let almanac = parse_almanac(sections)?;
 
     part1(&almanac);
     part2(almanac);
@@ -1,28 +1,16 @@
-use core::time;
+use lib::{error::LibResult, ints};
 
 fn part1() -> anyhow::Result<()> {
-    let mut lines = lib::input_lines("./input/day06.txt");
-    let time_and_distances = lines
-        .next()
-        .unwrap()
-        .split_whitespace()
-        .skip(1)
-        .map(|n| n.parse().map_err(anyhow::Error::from))
-        .zip(
-            lines
-                .next()
-                .unwrap()
-                .split_whitespace()
-                .skip(1)
-                .map(|n| n.parse().map_err(anyhow::Error::from)),
-        )
-        .map(|(time, dist)| match (time, dist) {
-            (Ok(t), Ok(d)) => Ok((t, d)),
-            (Ok(t), Err(e)) => Err(anyhow::anyhow!("Invalid distance for time {t}: {e}")),
-            (Err(e), Ok(d)) => Err(anyhow::anyhow!("Invalid time for distance {d}: {e}")),
-            (Err(et), Err(ed)) => Err(anyhow::anyhow!("Invalid time and distance {et}\n{ed}")),
-        })
-        .collect::<anyhow::Result<Vec<(usize, usize)>>>()?;
+    let mut lines = lib::input_lines("./input/day06.txt")?;
+    let mut next_numbers = || -> anyhow::Result<Vec<usize>> {
+        let line = lines
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Missing line in input"))??;
+        Ok(ints::uints(&line).collect::<LibResult<_>>()?)
+    };
+    let times = next_numbers()?;
+    let distances = next_numbers()?;
+    let time_and_distances = times.into_iter().zip(distances).collect::<Vec<_>>();
 
     let computed_margin: usize = time_and_distances
         .iter()
@@ -39,10 +27,10 @@ fn part1() -> anyhow::Result<()> {
 }
 
 fn part2() -> anyhow::Result<()> {
-    let mut lines = lib::input_lines("./input/day06.txt");
+    let mut lines = lib::input_lines("./input/day06.txt")?;
     let time: usize = lines
         .next()
-        .unwrap()
+        .unwrap()?
         .split_once(':')
         .unwrap()
         .1
@@ -51,7 +39,7 @@ fn part2() -> anyhow::Result<()> {
         .parse()?;
     let distance: usize = lines
         .next()
-        .unwrap()
+        .unwrap()?
         .split_once(':')
         .unwrap()
         .1
@@ -33,7 +33,7 @@ impl<T> BTree<T> {
 
     /// Retrieves the root of the BTree, if any.
     pub fn get_root(&self) -> Option<&Node<T>> {
-        match self.nodes.get(0) {
+        match self.nodes.first() {
             Some(node_opt) => node_opt.as_ref(),
             None => None,
         }
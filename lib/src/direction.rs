@@ -1,5 +1,7 @@
 //! Direction module. Used to interact with various types.
 
+use std::str::FromStr;
+
 use thiserror::Error;
 
 use crate::error::LibError;
@@ -10,6 +12,12 @@ pub enum Error {
     /// Raised when an error occured while parsing from char.
     #[error("invalid char `{0}`")]
     TryFromChar(char),
+    /// Raised when an error occured while parsing from str.
+    #[error("invalid direction `{0}`")]
+    FromStr(String),
+    /// Raised when a diagonal [Direction8] is converted into a [Direction].
+    #[error("{0:?} isn't an orthogonal direction")]
+    NotOrthogonal(Direction8),
 }
 
 impl From<Error> for LibError {
@@ -19,7 +27,7 @@ impl From<Error> for LibError {
 }
 
 /// Represent the four directions in a 2D environment.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Direction {
     /// Up.
     Up,
@@ -44,3 +52,280 @@ impl TryFrom<char> for Direction {
         }
     }
 }
+
+/// A quarter turn, either to the left (counterclockwise) or to the right (clockwise).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Turn {
+    /// Counterclockwise.
+    Left,
+    /// Clockwise.
+    Right,
+}
+
+/// Parses `L` and `R`.
+impl TryFrom<char> for Turn {
+    type Error = LibError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(Error::TryFromChar(value).into()),
+        }
+    }
+}
+
+impl FromStr for Turn {
+    type Err = LibError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(char), None) => Self::try_from(char),
+            _ => Err(Error::FromStr(s.to_string()).into()),
+        }
+    }
+}
+
+/// Represent the eight directions in a 2D environment, diagonals included.
+/// Variants are ordered clockwise, starting from [Direction8::Up].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    /// Up.
+    Up,
+    /// Up and right.
+    UpRight,
+    /// Right.
+    Right,
+    /// Down and right.
+    DownRight,
+    /// Down.
+    Down,
+    /// Down and left.
+    DownLeft,
+    /// Left.
+    Left,
+    /// Up and left.
+    UpLeft,
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => Self::Up,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+            Direction::Right => Self::Right,
+        }
+    }
+}
+
+/// Fails for diagonal directions.
+impl TryFrom<Direction8> for Direction {
+    type Error = LibError;
+
+    fn try_from(value: Direction8) -> Result<Self, Self::Error> {
+        match value {
+            Direction8::Up => Ok(Self::Up),
+            Direction8::Down => Ok(Self::Down),
+            Direction8::Left => Ok(Self::Left),
+            Direction8::Right => Ok(Self::Right),
+            _ => Err(Error::NotOrthogonal(value).into()),
+        }
+    }
+}
+
+/// Parses the orthogonal directions from `^v<>`, `UDLR` or `NSWE`.
+impl TryFrom<char> for Direction8 {
+    type Error = LibError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'U' | 'N' => Ok(Self::Up),
+            'v' | 'D' | 'S' => Ok(Self::Down),
+            '<' | 'L' | 'W' => Ok(Self::Left),
+            '>' | 'R' | 'E' => Ok(Self::Right),
+            _ => Err(Error::TryFromChar(value).into()),
+        }
+    }
+}
+
+/// Parses a single char like [Direction8::try_from], or a diagonal as two chars from `UDLR` or `NSWE`,
+/// the vertical one first, like `NE` or `DL`.
+/// Example
+/// ```rust
+///    use lib::direction::Direction8;
+///
+///    assert_eq!("N".parse::<Direction8>().unwrap(), Direction8::Up);
+///    assert_eq!("v".parse::<Direction8>().unwrap(), Direction8::Down);
+///    assert_eq!("SW".parse::<Direction8>().unwrap(), Direction8::DownLeft);
+///    assert_eq!("UR".parse::<Direction8>().unwrap(), Direction8::UpRight);
+///    assert!("EN".parse::<Direction8>().is_err());
+/// ```
+impl FromStr for Direction8 {
+    type Err = LibError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" | "UR" => Ok(Self::UpRight),
+            "SE" | "DR" => Ok(Self::DownRight),
+            "SW" | "DL" => Ok(Self::DownLeft),
+            "NW" | "UL" => Ok(Self::UpLeft),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(char), None) => Self::try_from(char),
+                    _ => Err(Error::FromStr(s.to_string()).into()),
+                }
+            }
+        }
+    }
+}
+
+impl Direction8 {
+    /// All the directions, clockwise from [Direction8::Up].
+    pub const ALL: [Direction8; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// The orthogonal directions, clockwise from [Direction8::Up].
+    pub const ORTHOGONAL: [Direction8; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Retrieves the direction rotated clockwise by `eighths` eighths of a turn, negative values rotating
+    /// counterclockwise.
+    /// Example
+    /// ```rust
+    ///    use lib::direction::Direction8;
+    ///
+    ///    assert_eq!(Direction8::Up.rotate(1), Direction8::UpRight);
+    ///    assert_eq!(Direction8::Up.rotate(-3), Direction8::DownLeft);
+    /// ```
+    pub fn rotate(&self, eighths: isize) -> Direction8 {
+        Self::ALL[(*self as isize + eighths).rem_euclid(8) as usize]
+    }
+
+    /// Retrieves the direction after a quarter turn counterclockwise.
+    pub fn turn_left(&self) -> Direction8 {
+        self.rotate(-2)
+    }
+
+    /// Retrieves the direction after a quarter turn clockwise.
+    pub fn turn_right(&self) -> Direction8 {
+        self.rotate(2)
+    }
+
+    /// Retrieves the direction after a quarter `turn`.
+    pub fn turn(&self, turn: Turn) -> Direction8 {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    /// Retrieves the opposite direction.
+    pub fn reverse(&self) -> Direction8 {
+        self.rotate(4)
+    }
+
+    /// Tells if the direction is a diagonal one.
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+
+    /// Retrieves the `(dx, dy)` offset of a move towards the direction, [Direction8::Up] increasing `y` like
+    /// [Direction::Up] does in `apply_direction`. It can be used as a step for [crate::grid::Grid::ray].
+    /// Example
+    /// ```rust
+    ///    use lib::{direction::Direction8, grid::Grid, position::Position};
+    ///
+    ///    assert_eq!(Direction8::DownLeft.offset(), (-1, -1));
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let ray = grid.ray(&Position::new(2, 2), Direction8::DownLeft.offset()).unwrap();
+    ///    assert_eq!(ray.map(|(_, v)| *v).collect::<Vec<_>>(), vec![5, 1]);
+    /// ```
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, 1),
+            Self::UpRight => (1, 1),
+            Self::Right => (1, 0),
+            Self::DownRight => (1, -1),
+            Self::Down => (0, -1),
+            Self::DownLeft => (-1, -1),
+            Self::Left => (-1, 0),
+            Self::UpLeft => (-1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::error::LibError;
+
+    use super::{Direction, Direction8, Error, Turn};
+
+    #[test]
+    fn turns() {
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(
+                direction.turn(Turn::Right).turn(Turn::Right),
+                direction.reverse()
+            );
+            let (dx, dy) = direction.offset();
+            assert_eq!(direction.reverse().offset(), (-dx, -dy));
+            assert_eq!(direction.is_diagonal(), dx != 0 && dy != 0);
+        }
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::ALL.iter().collect::<HashSet<_>>().len(), 8);
+    }
+
+    #[test]
+    fn parse() {
+        for (s, expected) in [
+            ("U", "N"),
+            ("D", "S"),
+            ("L", "W"),
+            ("R", "E"),
+            ("^", "N"),
+            ("v", "S"),
+            ("<", "W"),
+            (">", "E"),
+        ] {
+            assert_eq!(
+                s.parse::<Direction8>().unwrap(),
+                expected.parse::<Direction8>().unwrap()
+            );
+        }
+        assert!(matches!(
+            "".parse::<Direction8>(),
+            Err(LibError::Direction(Error::FromStr(_)))
+        ));
+        assert!(matches!(
+            "X".parse::<Direction8>(),
+            Err(LibError::Direction(Error::TryFromChar('X')))
+        ));
+        assert_eq!("L".parse::<Turn>().unwrap(), Turn::Left);
+        assert!("LR".parse::<Turn>().is_err());
+
+        assert_eq!(
+            Direction::try_from(Direction8::Left).unwrap(),
+            Direction::Left
+        );
+        assert!(matches!(
+            Direction::try_from(Direction8::UpLeft),
+            Err(LibError::Direction(Error::NotOrthogonal(
+                Direction8::UpLeft
+            )))
+        ));
+    }
+}
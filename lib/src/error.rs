@@ -1,8 +1,10 @@
 //! Error module. Every errors coming out of the library must be `LibError`.
 
+use std::path::{Path, PathBuf};
+
 use thiserror::Error;
 
-use crate::{direction, grid, nposition, position};
+use crate::{direction, grid, hex, ints, nposition, pattern, position};
 
 /// Global Error type for the library.
 #[derive(Error, Debug)]
@@ -13,13 +15,50 @@ pub enum LibError {
     /// Wrapping Grid's error.
     #[error(transparent)]
     Grid(grid::Error),
+    /// Wrapping Hex's error.
+    #[error(transparent)]
+    Hex(hex::Error),
+    /// Raised when an input file can't be opened, read or parsed.
+    /// `line` is 1-based, and is `None` when the error isn't tied to a specific line.
+    #[error("{}{}: {source}", path.display(), line.map(|l| format!(":{l}")).unwrap_or_default())]
+    Input {
+        /// Path of the input file.
+        path: PathBuf,
+        /// 1-based line number where the error occured, if any.
+        /// When a block of lines is parsed as a whole, like a section, this is the first line of the block.
+        line: Option<usize>,
+        /// Underlying I/O or parse error.
+        #[source]
+        source: anyhow::Error,
+    },
+    /// Wrapping Ints's error.
+    #[error(transparent)]
+    Ints(ints::Error),
     /// Wrapping NPosition's error.
     #[error(transparent)]
     NPosition(nposition::Error),
+    /// Wrapping Pattern's error.
+    #[error(transparent)]
+    Pattern(pattern::Error),
     /// Wrapping Position's error.
     #[error(transparent)]
     Position(position::Error),
 }
 
+impl LibError {
+    /// Creates a `LibError::Input` from the path, the line and the underlying error.
+    pub(crate) fn input(
+        path: impl AsRef<Path>,
+        line: Option<usize>,
+        source: impl Into<anyhow::Error>,
+    ) -> Self {
+        LibError::Input {
+            path: path.as_ref().to_path_buf(),
+            line,
+            source: source.into(),
+        }
+    }
+}
+
 /// Reexport of the `Result` type, locking the error type.
 pub type LibResult<T> = Result<T, LibError>;
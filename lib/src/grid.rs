@@ -1,8 +1,7 @@
 //! Grid module. This module aims to provide useful and easy to manipulate grids.
 
-use std::fmt::Display;
+use std::{convert::Infallible, str::FromStr};
 
-use colored::Colorize;
 use thiserror::Error;
 
 use crate::{
@@ -10,6 +9,35 @@ use crate::{
     position::Position,
 };
 
+mod automaton;
+mod bits;
+mod growing;
+mod nd;
+mod path;
+mod prefix;
+mod ray;
+mod region;
+mod render;
+mod search;
+mod sparse;
+mod transform;
+mod view;
+mod wrapping;
+
+pub use automaton::{Automaton, Cycle, Neighbors, Topology};
+pub use bits::BitGrid;
+pub use growing::GrowingGrid;
+pub use nd::GridN;
+pub use path::Path;
+pub use prefix::{DiffGrid, SummedArea};
+pub use region::{Component, Connectivity};
+pub use render::GridRenderer;
+pub use search::{StampMatch, WordMatch};
+pub use sparse::SparseGrid;
+pub use transform::{Transform, TransformView};
+pub use view::{GridView, GridViewMut};
+pub use wrapping::WrappingGrid;
+
 /// Error regarding Grid manipulations.
 #[derive(Error, Debug)]
 pub enum Error {
@@ -25,9 +53,47 @@ pub enum Error {
     /// Interacting with an invalid rectangle.
     #[error("{0:?} and {1:?} can't be used to draw a rectangle")]
     InvalidRectangle(Position, Position),
+    /// Raised when two grids of different sizes, as `(nb_row, nb_col)`, are combined.
+    #[error("grids of sizes {0:?} and {1:?} can't be combined")]
+    SizeMismatch((usize, usize), (usize, usize)),
+    /// Interacting with invalid coordinates of a [GridN].
+    #[error("{0:?} can't be reached")]
+    InvalidCoordinates(Vec<usize>),
+    /// Interacting with an axis a [GridN] doesn't have.
+    #[error("{0} is an invalid axis")]
+    InvalidAxis(usize),
+    /// Slicing a [GridN] at an invalid index, as `(axis, index)`.
+    #[error("{1} is an invalid index along axis {0}")]
+    InvalidSlice(usize, usize),
+    /// Raised when an empty grid is used where at least one cell is needed.
+    #[error("the grid is empty")]
+    EmptyGrid,
     /// Raised when an error occured while using the TryFrom impl.
     #[error("can't create Grid, Vec size doesn't correspond")]
     TryFrom,
+    /// Raised when a row doesn't have the same length as the first one.
+    #[error("row {row} has {found} cells, {expected} expected")]
+    RaggedRow {
+        /// Index of the faulty row.
+        row: usize,
+        /// Number of cells of the first row.
+        expected: usize,
+        /// Number of cells of the faulty row.
+        found: usize,
+    },
+    /// Raised when a column doesn't have one cell per row.
+    #[error("col {col} has {found} cells, {expected} expected")]
+    RaggedCol {
+        /// Index of the faulty column.
+        col: usize,
+        /// Number of rows.
+        expected: usize,
+        /// Number of cells of the faulty column.
+        found: usize,
+    },
+    /// Raised when a cell can't be created from its char.
+    #[error("can't parse cell at {0:?}: {1}")]
+    Parse(Position, #[source] anyhow::Error),
 }
 
 impl From<Error> for LibError {
@@ -36,6 +102,64 @@ impl From<Error> for LibError {
     }
 }
 
+/// Offsets of the 4 orthogonal neighbours, in row-major order.
+const NEIGHBORS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the 8 surrounding neighbours, in row-major order.
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Checks that `top_left` and `bottom_right` define a rectangle inside a grid of `nb_row` rows and `nb_col` columns.
+fn check_rect(
+    nb_row: usize,
+    nb_col: usize,
+    top_left: &Position,
+    bottom_right: &Position,
+) -> LibResult<()> {
+    if top_left.x() >= nb_col || top_left.y() >= nb_row {
+        Err(LibError::from(Error::InvalidPosition(*top_left)))
+    } else if bottom_right.x() >= nb_col || bottom_right.y() >= nb_row {
+        Err(LibError::from(Error::InvalidPosition(*bottom_right)))
+    } else if top_left.x() > bottom_right.x() || top_left.y() > bottom_right.y() {
+        Err(LibError::from(Error::InvalidRectangle(
+            *top_left,
+            *bottom_right,
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Holds the internal state of an [Iterator] of mutable references over distinct cells.
+/// `indexes` must be sorted and without duplicates.
+struct DisjointIterMut<'a, T> {
+    rest: &'a mut [T],
+    consumed: usize,
+    indexes: std::vec::IntoIter<(usize, Position)>,
+}
+
+impl<'a, T> Iterator for DisjointIterMut<'a, T> {
+    type Item = (Position, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, pos) = self.indexes.next()?;
+        let rest = std::mem::take(&mut self.rest);
+        let (cell, rest) = rest[index - self.consumed..].split_first_mut()?;
+        self.rest = rest;
+        self.consumed = index + 1;
+
+        Some((pos, cell))
+    }
+}
+
 /// A two dimension Grid, with fancy and easy to use methods.
 #[derive(Debug)]
 pub struct Grid<T> {
@@ -51,7 +175,7 @@ impl<T> TryFrom<(Vec<T>, usize)> for Grid<T> {
 
     fn try_from(value: (Vec<T>, usize)) -> Result<Self, Self::Error> {
         let vec_len = value.0.len();
-        if vec_len % value.1 == 0 {
+        if vec_len.checked_rem(value.1) == Some(0) {
             Ok(Self {
                 grid: value.0,
                 nb_row: vec_len / value.1,
@@ -63,7 +187,92 @@ impl<T> TryFrom<(Vec<T>, usize)> for Grid<T> {
     }
 }
 
+/// Creates a Grid of chars from a text block, one row per line. See [Grid::parse_with].
+impl FromStr for Grid<char> {
+    type Err = LibError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |_, c| Ok::<_, Infallible>(c))
+    }
+}
+
 impl<T> Grid<T> {
+    /// Creates a Grid from a text block, one row per line, mapping every char with `f`.
+    /// The width is the length of the first row, and every other row must have the same length.
+    /// Both `\n` and `\r\n` line endings are supported.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::parse_with("123\n456\n", |_, c| {
+    ///            c.to_digit(10).ok_or_else(|| anyhow::anyhow!("{c} isn't a digit"))
+    ///        }).unwrap();
+    ///    assert_eq!((grid.nb_row(), grid.nb_col()), (2, 3));
+    ///    assert_eq!(grid.get(&Position::new(2, 1)).unwrap(), &6);
+    ///    assert!(Grid::<char>::parse_with("12\n3", |_, c| Ok::<_, anyhow::Error>(c)).is_err());
+    /// ```
+    pub fn parse_with<E, F>(text: &str, mut f: F) -> LibResult<Self>
+    where
+        F: FnMut(Position, char) -> Result<T, E>,
+        E: Into<anyhow::Error>,
+    {
+        let mut grid = Vec::new();
+        let mut nb_col = None;
+        let mut nb_row = 0;
+
+        for (y, line) in text.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let found = line.chars().count();
+            match nb_col {
+                Some(expected) if expected != found => {
+                    return Err(LibError::from(Error::RaggedRow {
+                        row: y,
+                        expected,
+                        found,
+                    }))
+                }
+                Some(_) => (),
+                None => nb_col = Some(found),
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                let pos = Position::new(x, y);
+                grid.push(f(pos, c).map_err(|e| LibError::from(Error::Parse(pos, e.into())))?);
+            }
+            nb_row += 1;
+        }
+
+        Ok(Self {
+            grid,
+            nb_row,
+            nb_col: nb_col.unwrap_or_default(),
+        })
+    }
+
+    /// Retrieves the number of rows of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    assert_eq!(grid.nb_row(), 2);
+    /// ```
+    pub fn nb_row(&self) -> usize {
+        self.nb_row
+    }
+
+    /// Retrieves the number of columns of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    assert_eq!(grid.nb_col(), 3);
+    /// ```
+    pub fn nb_col(&self) -> usize {
+        self.nb_col
+    }
+
     /// Retrieves a reference to data at a given position. Error is raised if the position is out of the [Grid].
     /// Example
     /// ```rust
@@ -108,7 +317,10 @@ impl<T> Grid<T> {
     ///        ], 3)).unwrap();
     ///    assert_eq!(grid.iter_row(1).unwrap().collect::<Vec<_>>(), vec![&4, &5, &6]);
     /// ```
-    pub fn iter_row(&self, row: usize) -> LibResult<impl Iterator<Item = &T>> {
+    pub fn iter_row(
+        &self,
+        row: usize,
+    ) -> LibResult<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
         (row < self.nb_row)
             .then(|| self.grid[row * self.nb_col..(row * self.nb_col + self.nb_col)].iter())
             .ok_or_else(|| LibError::from(Error::InvalidRow(row)))
@@ -124,7 +336,10 @@ impl<T> Grid<T> {
     ///        ], 3)).unwrap();
     ///    assert_eq!(grid.iter_row_mut(1).unwrap().collect::<Vec<_>>(), vec![&mut 4, &mut 5, &mut 6]);
     /// ```
-    pub fn iter_row_mut(&mut self, row: usize) -> LibResult<impl Iterator<Item = &mut T>> {
+    pub fn iter_row_mut(
+        &mut self,
+        row: usize,
+    ) -> LibResult<impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator> {
         (row < self.nb_row)
             .then(|| self.grid[row * self.nb_col..(row * self.nb_col + self.nb_col)].iter_mut())
             .ok_or_else(|| LibError::from(Error::InvalidRow(row)))
@@ -140,7 +355,10 @@ impl<T> Grid<T> {
     ///        ], 3)).unwrap();
     ///    assert_eq!(grid.iter_col(1).unwrap().collect::<Vec<_>>(), vec![&2, &5, &8]);
     /// ```
-    pub fn iter_col(&self, col: usize) -> LibResult<impl Iterator<Item = &T>> {
+    pub fn iter_col(
+        &self,
+        col: usize,
+    ) -> LibResult<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
         (col < self.nb_col)
             .then(|| self.grid.iter().skip(col).step_by(self.nb_col))
             .ok_or_else(|| LibError::from(Error::InvalidCol(col)))
@@ -156,7 +374,10 @@ impl<T> Grid<T> {
     ///        ], 3)).unwrap();
     ///    assert_eq!(grid.iter_col_mut(1).unwrap().collect::<Vec<_>>(), vec![&mut 2, &mut 5, &mut 8]);
     /// ```
-    pub fn iter_col_mut(&mut self, col: usize) -> LibResult<impl Iterator<Item = &mut T>> {
+    pub fn iter_col_mut(
+        &mut self,
+        col: usize,
+    ) -> LibResult<impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator> {
         (col < self.nb_col)
             .then(|| self.grid.iter_mut().skip(col).step_by(self.nb_col))
             .ok_or_else(|| LibError::from(Error::InvalidCol(col)))
@@ -178,25 +399,17 @@ impl<T> Grid<T> {
         top_left: Position,
         bottom_right: Position,
     ) -> LibResult<impl Iterator<Item = &T>> {
-        if top_left.x() >= self.nb_col || top_left.y() >= self.nb_row {
-            Err(LibError::from(Error::InvalidPosition(top_left)))
-        } else if bottom_right.x() >= self.nb_col || bottom_right.y() >= self.nb_row {
-            Err(LibError::from(Error::InvalidPosition(bottom_right)))
-        } else if top_left.x() > bottom_right.x() || top_left.y() > bottom_right.y() {
-            Err(LibError::from(Error::InvalidRectangle(
-                top_left,
-                bottom_right,
-            )))
-        } else {
-            Ok(self.grid
-                [top_left.y() * self.nb_col..(bottom_right.y() * self.nb_col + self.nb_col)]
+        check_rect(self.nb_row, self.nb_col, &top_left, &bottom_right)?;
+
+        Ok(
+            self.grid[top_left.y() * self.nb_col..(bottom_right.y() * self.nb_col + self.nb_col)]
                 .chunks_exact(self.nb_col)
                 .flat_map(move |row| {
                     row.iter()
                         .skip(top_left.x())
                         .take(bottom_right.x() - top_left.x() + 1)
-                }))
-        }
+                }),
+        )
     }
 
     /// Yields an [Iterator] of mutable references over the rectangle defined by `top_left` and `bottom_right` positions.
@@ -215,25 +428,17 @@ impl<T> Grid<T> {
         top_left: Position,
         bottom_right: Position,
     ) -> LibResult<impl Iterator<Item = &mut T>> {
-        if top_left.x() >= self.nb_col || top_left.y() >= self.nb_row {
-            Err(LibError::from(Error::InvalidPosition(top_left)))
-        } else if bottom_right.x() >= self.nb_col || bottom_right.y() >= self.nb_row {
-            Err(LibError::from(Error::InvalidPosition(bottom_right)))
-        } else if top_left.x() > bottom_right.x() || top_left.y() > bottom_right.y() {
-            Err(LibError::from(Error::InvalidRectangle(
-                top_left,
-                bottom_right,
-            )))
-        } else {
-            Ok(self.grid
-                [top_left.y() * self.nb_col..(bottom_right.y() * self.nb_col + self.nb_col)]
+        check_rect(self.nb_row, self.nb_col, &top_left, &bottom_right)?;
+
+        Ok(
+            self.grid[top_left.y() * self.nb_col..(bottom_right.y() * self.nb_col + self.nb_col)]
                 .chunks_exact_mut(self.nb_col)
                 .flat_map(move |row| {
                     row.iter_mut()
                         .skip(top_left.x())
                         .take(bottom_right.x() - top_left.x() + 1)
-                }))
-        }
+                }),
+        )
     }
 
     /// Yields an [Iterator| of references over the whole [Grid]. The data is traversed row by row.
@@ -267,34 +472,364 @@ impl<T> Grid<T> {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
         self.grid.iter_mut()
     }
-}
 
-impl Display for Grid<bool> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut top_bot = "-".repeat(self.nb_col + 2);
-        top_bot.push('\n');
-        f.write_str(&top_bot)?;
+    /// Private method retrieving the [Position] of the cell stored at `index`.
+    fn position(&self, index: usize) -> Position {
+        Position::new(index % self.nb_col, index / self.nb_col)
+    }
 
-        for (i, b) in self.grid.iter().enumerate() {
-            if i % self.nb_col == 0 {
-                f.write_str("|")?;
-            }
-            match b {
-                true => write!(f, "{}", "t".green())?,
-                false => write!(f, "{}", "f".red())?,
-            }
-            if i % self.nb_col == self.nb_col - 1 {
-                f.write_str("|\n")?;
-            }
+    /// Private method yielding every position of the rectangle defined by `top_left` and `bottom_right`, row by row.
+    fn rect_positions(
+        top_left: Position,
+        bottom_right: Position,
+    ) -> impl Iterator<Item = Position> {
+        (top_left.y()..=bottom_right.y())
+            .flat_map(move |y| (top_left.x()..=bottom_right.x()).map(move |x| Position::new(x, y)))
+    }
+
+    /// Yields an [Iterator] of positions and references over the whole [Grid]. The data is traversed row by row.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4], 2)).unwrap();
+    ///    assert_eq!(grid.enumerate().collect::<Vec<_>>(), vec![
+    ///            (Position::new(0, 0), &1), (Position::new(1, 0), &2),
+    ///            (Position::new(0, 1), &3), (Position::new(1, 1), &4),
+    ///        ]);
+    /// ```
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.grid
+            .iter()
+            .enumerate()
+            .map(|(index, v)| (self.position(index), v))
+    }
+
+    /// Yields an [Iterator] of positions and mutable references over the whole [Grid]. The data is traversed row by row.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![0; 4], 2)).unwrap();
+    ///    grid.enumerate_mut().for_each(|(pos, v)| *v = pos.x() + 10 * pos.y());
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&0, &1, &10, &11]);
+    /// ```
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        let nb_col = self.nb_col;
+        self.grid
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, v)| (Position::new(index % nb_col, index / nb_col), v))
+    }
+
+    /// Yields an [Iterator] of positions and references over the row `row`. Error is raised if the row doesn't exist.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4], 2)).unwrap();
+    ///    assert_eq!(grid.enumerate_row(1).unwrap().collect::<Vec<_>>(), vec![
+    ///            (Position::new(0, 1), &3), (Position::new(1, 1), &4),
+    ///        ]);
+    /// ```
+    pub fn enumerate_row(&self, row: usize) -> LibResult<impl Iterator<Item = (Position, &T)>> {
+        Ok(self
+            .iter_row(row)?
+            .enumerate()
+            .map(move |(x, v)| (Position::new(x, row), v)))
+    }
+
+    /// Yields an [Iterator] of positions and mutable references over the row `row`. Error is raised if the row doesn't exist.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![1, 2, 3, 4], 2)).unwrap();
+    ///    grid.enumerate_row_mut(1).unwrap().for_each(|(pos, v)| *v = pos.x());
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &2, &0, &1]);
+    /// ```
+    pub fn enumerate_row_mut(
+        &mut self,
+        row: usize,
+    ) -> LibResult<impl Iterator<Item = (Position, &mut T)>> {
+        Ok(self
+            .iter_row_mut(row)?
+            .enumerate()
+            .map(move |(x, v)| (Position::new(x, row), v)))
+    }
+
+    /// Yields an [Iterator] of positions and references over the column `col`. Error is raised if the column doesn't exist.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4], 2)).unwrap();
+    ///    assert_eq!(grid.enumerate_col(1).unwrap().collect::<Vec<_>>(), vec![
+    ///            (Position::new(1, 0), &2), (Position::new(1, 1), &4),
+    ///        ]);
+    /// ```
+    pub fn enumerate_col(&self, col: usize) -> LibResult<impl Iterator<Item = (Position, &T)>> {
+        Ok(self
+            .iter_col(col)?
+            .enumerate()
+            .map(move |(y, v)| (Position::new(col, y), v)))
+    }
+
+    /// Yields an [Iterator] of positions and mutable references over the column `col`. Error is raised if the column doesn't exist.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![1, 2, 3, 4], 2)).unwrap();
+    ///    grid.enumerate_col_mut(1).unwrap().for_each(|(pos, v)| *v = pos.y());
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &0, &3, &1]);
+    /// ```
+    pub fn enumerate_col_mut(
+        &mut self,
+        col: usize,
+    ) -> LibResult<impl Iterator<Item = (Position, &mut T)>> {
+        Ok(self
+            .iter_col_mut(col)?
+            .enumerate()
+            .map(move |(y, v)| (Position::new(col, y), v)))
+    }
+
+    /// Yields an [Iterator] of positions and references over the rectangle defined by `top_left` and `bottom_right` positions.
+    /// Errors are the same as [Grid::iter_rect].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![
+    ///            1, 2, 3, 4, 5, 6, 7, 8, 9
+    ///        ], 3)).unwrap();
+    ///    assert_eq!(grid.enumerate_rect(Position::new(1, 1), Position::new(2, 1)).unwrap().collect::<Vec<_>>(), vec![
+    ///            (Position::new(1, 1), &5), (Position::new(2, 1), &6),
+    ///        ]);
+    /// ```
+    pub fn enumerate_rect(
+        &self,
+        top_left: Position,
+        bottom_right: Position,
+    ) -> LibResult<impl Iterator<Item = (Position, &T)>> {
+        Ok(Self::rect_positions(top_left, bottom_right)
+            .zip(self.iter_rect(top_left, bottom_right)?))
+    }
+
+    /// Yields an [Iterator] of positions and mutable references over the rectangle defined by `top_left` and
+    /// `bottom_right` positions. Errors are the same as [Grid::iter_rect_mut].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![
+    ///            1, 2, 3, 4, 5, 6, 7, 8, 9
+    ///        ], 3)).unwrap();
+    ///    grid.enumerate_rect_mut(Position::new(1, 1), Position::new(2, 1)).unwrap().for_each(|(pos, v)| *v = pos.x());
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &1, &2, &7, &8, &9]);
+    /// ```
+    pub fn enumerate_rect_mut(
+        &mut self,
+        top_left: Position,
+        bottom_right: Position,
+    ) -> LibResult<impl Iterator<Item = (Position, &mut T)>> {
+        Ok(Self::rect_positions(top_left, bottom_right)
+            .zip(self.iter_rect_mut(top_left, bottom_right)?))
+    }
+
+    /// Retrieves the [Position] of the first cell, row by row, matching `predicate`.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid: Grid<char> = "Sab\nbcE".parse().unwrap();
+    ///    assert_eq!(grid.find(|c| c.is_uppercase()), Some(Position::new(0, 0)));
+    ///    assert_eq!(grid.find(|c| c.is_ascii_digit()), None);
+    /// ```
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.grid
+            .iter()
+            .position(predicate)
+            .map(|index| self.position(index))
+    }
+
+    /// Retrieves the [Position] of the first cell, row by row, equal to `value`.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid: Grid<char> = "Sab\nbcE".parse().unwrap();
+    ///    assert_eq!(grid.position_of(&'E'), Some(Position::new(2, 1)));
+    /// ```
+    pub fn position_of(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.find(|v| v == value)
+    }
+
+    /// Private method retrieving the indexes and positions of the cells at `offsets` of `pos`, in row-major order.
+    /// Offsets leading out of the [Grid] are ignored. Error is raised if `pos` is out of the [Grid].
+    fn neighbor_indexes(
+        &self,
+        pos: &Position,
+        offsets: &[(isize, isize)],
+    ) -> LibResult<Vec<(usize, Position)>> {
+        if pos.x() >= self.nb_col || pos.y() >= self.nb_row {
+            return Err(LibError::from(Error::InvalidPosition(*pos)));
         }
 
-        f.write_str(&top_bot)?;
-        Ok(())
+        let mut indexes = offsets
+            .iter()
+            .filter_map(|&(dx, dy)| {
+                let x = pos
+                    .x()
+                    .checked_add_signed(dx)
+                    .filter(|&x| x < self.nb_col)?;
+                let y = pos
+                    .y()
+                    .checked_add_signed(dy)
+                    .filter(|&y| y < self.nb_row)?;
+                Some((y * self.nb_col + x, Position::new(x, y)))
+            })
+            .collect::<Vec<_>>();
+        indexes.sort_unstable_by_key(|&(index, _)| index);
+        indexes.dedup_by_key(|&mut (index, _)| index);
+
+        Ok(indexes)
+    }
+
+    /// Yields an [Iterator] of positions and references over the cells at `offsets` of `pos`, in row-major order.
+    /// An offset is a `(dx, dy)` tuple, and offsets leading out of the [Grid] are skipped.
+    /// Error is raised if `pos` is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![
+    ///            1, 2, 3, 4, 5, 6, 7, 8, 9
+    ///        ], 3)).unwrap();
+    ///    // Knight moves.
+    ///    let knight = [(1, 2), (2, 1), (-1, 2), (-2, 1), (1, -2), (2, -1), (-1, -2), (-2, -1)];
+    ///    assert_eq!(grid.neighbors_with_positions(&Position::new(0, 0), &knight).unwrap().collect::<Vec<_>>(), vec![
+    ///            (Position::new(2, 1), &6), (Position::new(1, 2), &8)
+    ///        ]);
+    /// ```
+    pub fn neighbors_with_positions(
+        &self,
+        pos: &Position,
+        offsets: &[(isize, isize)],
+    ) -> LibResult<impl Iterator<Item = (Position, &T)>> {
+        Ok(self
+            .neighbor_indexes(pos, offsets)?
+            .into_iter()
+            .map(|(index, pos)| (pos, &self.grid[index])))
+    }
+
+    /// Yields an [Iterator] of positions and mutable references over the cells at `offsets` of `pos`, in row-major order.
+    /// An offset is a `(dx, dy)` tuple, and offsets leading out of the [Grid] are skipped.
+    /// Error is raised if `pos` is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![
+    ///            1, 2, 3, 4, 5, 6, 7, 8, 9
+    ///        ], 3)).unwrap();
+    ///    grid.neighbors_with_positions_mut(&Position::new(1, 1), &[(-1, -1), (1, 1)]).unwrap().for_each(|(_, v)| *v = 0);
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&0, &2, &3, &4, &5, &6, &7, &8, &0]);
+    /// ```
+    pub fn neighbors_with_positions_mut(
+        &mut self,
+        pos: &Position,
+        offsets: &[(isize, isize)],
+    ) -> LibResult<impl Iterator<Item = (Position, &mut T)>> {
+        let indexes = self.neighbor_indexes(pos, offsets)?;
+        Ok(DisjointIterMut {
+            rest: &mut self.grid,
+            consumed: 0,
+            indexes: indexes.into_iter(),
+        })
+    }
+
+    /// Yields an [Iterator] of positions and references over the 4 orthogonal neighbours of `pos`, in row-major order.
+    /// Neighbours out of the [Grid] are skipped. Error is raised if `pos` is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![
+    ///            1, 2, 3, 4, 5, 6, 7, 8, 9
+    ///        ], 3)).unwrap();
+    ///    assert_eq!(grid.neighbors4(&Position::new(0, 1)).unwrap().collect::<Vec<_>>(), vec![
+    ///            (Position::new(0, 0), &1), (Position::new(1, 1), &5), (Position::new(0, 2), &7)
+    ///        ]);
+    /// ```
+    pub fn neighbors4(&self, pos: &Position) -> LibResult<impl Iterator<Item = (Position, &T)>> {
+        self.neighbors_with_positions(pos, &NEIGHBORS4)
+    }
+
+    /// Yields an [Iterator] of positions and mutable references over the 4 orthogonal neighbours of `pos`, in row-major order.
+    /// Neighbours out of the [Grid] are skipped. Error is raised if `pos` is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![
+    ///            1, 2, 3, 4, 5, 6, 7, 8, 9
+    ///        ], 3)).unwrap();
+    ///    grid.neighbors4_mut(&Position::new(1, 1)).unwrap().for_each(|(_, v)| *v = 0);
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &0, &3, &0, &5, &0, &7, &0, &9]);
+    /// ```
+    pub fn neighbors4_mut(
+        &mut self,
+        pos: &Position,
+    ) -> LibResult<impl Iterator<Item = (Position, &mut T)>> {
+        self.neighbors_with_positions_mut(pos, &NEIGHBORS4)
+    }
+
+    /// Yields an [Iterator] of positions and references over the 8 surrounding neighbours of `pos`, diagonals included,
+    /// in row-major order. Neighbours out of the [Grid] are skipped. Error is raised if `pos` is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![
+    ///            1, 2, 3, 4, 5, 6, 7, 8, 9
+    ///        ], 3)).unwrap();
+    ///    assert_eq!(grid.neighbors8(&Position::new(2, 2)).unwrap().collect::<Vec<_>>(), vec![
+    ///            (Position::new(1, 1), &5), (Position::new(2, 1), &6), (Position::new(1, 2), &8)
+    ///        ]);
+    /// ```
+    pub fn neighbors8(&self, pos: &Position) -> LibResult<impl Iterator<Item = (Position, &T)>> {
+        self.neighbors_with_positions(pos, &NEIGHBORS8)
+    }
+
+    /// Yields an [Iterator] of positions and mutable references over the 8 surrounding neighbours of `pos`,
+    /// diagonals included, in row-major order. Neighbours out of the [Grid] are skipped.
+    /// Error is raised if `pos` is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![
+    ///            1, 2, 3, 4, 5, 6, 7, 8, 9
+    ///        ], 3)).unwrap();
+    ///    grid.neighbors8_mut(&Position::new(1, 1)).unwrap().for_each(|(_, v)| *v = 0);
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&0, &0, &0, &0, &5, &0, &0, &0, &0]);
+    /// ```
+    pub fn neighbors8_mut(
+        &mut self,
+        pos: &Position,
+    ) -> LibResult<impl Iterator<Item = (Position, &mut T)>> {
+        self.neighbors_with_positions_mut(pos, &NEIGHBORS8)
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
+
     use crate::{
         error::LibError,
         grid::{Error, Grid},
@@ -349,6 +884,59 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn from_str() -> anyhow::Result<()> {
+        let g: Grid<char> = "467..\r\n...*.\r\n..35.\r\n".parse()?;
+        assert_eq!((g.nb_row, g.nb_col), (3, 5));
+        assert_eq!(g.get(&Position::new(3, 1))?, &'*');
+        assert_eq!(g.iter_row(2)?.collect::<String>(), "..35.");
+
+        let g: Grid<char> = "".parse()?;
+        assert_eq!((g.nb_row, g.nb_col), (0, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_with() -> anyhow::Result<()> {
+        let g = Grid::parse_with("30373\n25512\n", |pos, c| {
+            c.to_digit(10)
+                .map(|d| (pos, d as u8))
+                .ok_or_else(|| anyhow::anyhow!("{c} isn't a digit"))
+        })?;
+        assert_eq!(g.get(&Position::new(4, 1))?, &(Position::new(4, 1), 2));
+
+        // Ragged rows.
+        assert!(matches!(
+            Grid::<char>::from_str("abc\nab\nabc"),
+            Err(LibError::Grid(Error::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            }))
+        ));
+        assert!(matches!(
+            Grid::<char>::from_str("abc\nabcd"),
+            Err(LibError::Grid(Error::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 4
+            }))
+        ));
+
+        // Mapper error.
+        let res = Grid::parse_with("12\n3x", |_, c| {
+            c.to_digit(10)
+                .ok_or_else(|| anyhow::anyhow!("{c} isn't a digit"))
+        });
+        assert!(matches!(
+            res,
+            Err(LibError::Grid(Error::Parse(pos, _))) if pos == Position::new(1, 1)
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn get() -> anyhow::Result<()> {
         let g = Grid::try_from((
@@ -664,6 +1252,150 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn enumerate() -> anyhow::Result<()> {
+        let mut g = Grid::try_from((
+            vec![
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+            ],
+            3,
+        ))?;
+
+        assert!(g
+            .enumerate()
+            .all(|(pos, v)| g.get(&pos).is_ok_and(|value| value == v)));
+        assert_eq!(
+            g.enumerate_row(5)?.collect::<Vec<_>>(),
+            vec![
+                (Position::new(0, 5), &16),
+                (Position::new(1, 5), &17),
+                (Position::new(2, 5), &18)
+            ]
+        );
+        assert_eq!(
+            g.enumerate_col(2)?
+                .map(|(pos, _)| pos.y())
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            g.enumerate_rect(Position::new(1, 3), Position::new(2, 4))?
+                .collect::<Vec<_>>(),
+            vec![
+                (Position::new(1, 3), &11),
+                (Position::new(2, 3), &12),
+                (Position::new(1, 4), &14),
+                (Position::new(2, 4), &15)
+            ]
+        );
+        assert!(matches!(
+            g.enumerate_row(6),
+            Err(LibError::Grid(Error::InvalidRow(6)))
+        ));
+        assert!(matches!(
+            g.enumerate_col_mut(3),
+            Err(LibError::Grid(Error::InvalidCol(3)))
+        ));
+        assert!(matches!(
+            g.enumerate_rect_mut(Position::new(2, 2), Position::new(1, 1)),
+            Err(LibError::Grid(Error::InvalidRectangle(_, _)))
+        ));
+
+        g.enumerate_mut()
+            .for_each(|(pos, v)| *v = pos.x() * 100 + pos.y());
+        assert_eq!(g.get(&Position::new(2, 4))?, &204);
+        g.enumerate_row_mut(0)?.for_each(|(_, v)| *v = 0);
+        g.enumerate_col_mut(0)?.for_each(|(_, v)| *v = 0);
+        g.enumerate_rect_mut(Position::new(1, 1), Position::new(2, 5))?
+            .for_each(|(_, v)| *v = 0);
+        assert!(g.iter().all(|v| *v == 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn find() -> anyhow::Result<()> {
+        let g = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 8], 5))?;
+
+        assert_eq!(g.find(|v| *v > 5), Some(Position::new(0, 1)));
+        assert_eq!(g.find(|v| *v > 9), None);
+        assert_eq!(g.position_of(&8), Some(Position::new(2, 1)));
+        assert_eq!(g.position_of(&0), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn neighbors() -> anyhow::Result<()> {
+        let mut g = Grid::try_from((
+            vec![
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+            ],
+            3,
+        ))?;
+
+        // Center of the grid.
+        assert_eq!(
+            g.neighbors4(&Position::new(1, 2))?
+                .map(|(_, v)| v)
+                .collect::<Vec<_>>(),
+            vec![&5, &7, &9, &11]
+        );
+        assert_eq!(
+            g.neighbors8(&Position::new(1, 2))?
+                .map(|(_, v)| v)
+                .collect::<Vec<_>>(),
+            vec![&4, &5, &6, &7, &9, &10, &11, &12]
+        );
+
+        // Corners are clipped.
+        assert_eq!(
+            g.neighbors8(&Position::new(2, 5))?.collect::<Vec<_>>(),
+            vec![
+                (Position::new(1, 4), &14),
+                (Position::new(2, 4), &15),
+                (Position::new(1, 5), &17)
+            ]
+        );
+        assert_eq!(
+            g.neighbors4(&Position::new(0, 0))?.collect::<Vec<_>>(),
+            vec![(Position::new(1, 0), &2), (Position::new(0, 1), &4)]
+        );
+
+        // Custom offsets, with duplicates and out of grid ones.
+        assert_eq!(
+            g.neighbors_with_positions(&Position::new(0, 0), &[(0, 5), (2, 0), (0, 5), (-1, 0)])?
+                .collect::<Vec<_>>(),
+            vec![(Position::new(2, 0), &3), (Position::new(0, 5), &16)]
+        );
+
+        // Mutable neighbours.
+        g.neighbors8_mut(&Position::new(0, 3))?
+            .for_each(|(_, v)| *v = 0);
+        assert_eq!(
+            g.iter().collect::<Vec<_>>(),
+            vec![&1, &2, &3, &4, &5, &6, &0, &0, &9, &10, &0, &12, &0, &0, &15, &16, &17, &18]
+        );
+        assert_eq!(
+            g.neighbors4_mut(&Position::new(2, 5))?
+                .map(|(pos, v)| (pos, *v))
+                .collect::<Vec<_>>(),
+            vec![(Position::new(2, 4), 15), (Position::new(1, 5), 17)]
+        );
+
+        // Position out of the grid.
+        assert!(matches!(
+            g.neighbors4(&Position::new(3, 0)),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+        assert!(matches!(
+            g.neighbors8_mut(&Position::new(0, 6)),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn iter() -> anyhow::Result<()> {
         let g = Grid::try_from((
@@ -0,0 +1,423 @@
+//! Automaton module. Double-buffered cellular automaton stepping over a [Grid].
+
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{
+    error::{LibError, LibResult},
+    grid::{Connectivity, Error, Grid},
+    position::Position,
+};
+
+/// [Iterator] over the neighbours of a cell given to an automaton rule, see [Grid::step] and [Automaton::step].
+/// Yields positions and references in row-major order, neighbours out of the [Grid] being skipped.
+pub struct Neighbors<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Position,
+    offsets: std::slice::Iter<'static, (isize, isize)>,
+}
+
+impl<'a, T> Clone for Neighbors<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            pos: self.pos,
+            offsets: self.offsets.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = (Position, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &(dx, dy) in self.offsets.by_ref() {
+            let x = self.pos.x().checked_add_signed(dx);
+            let y = self.pos.y().checked_add_signed(dy);
+            if let (Some(x), Some(y)) = (x, y) {
+                if x < self.grid.nb_col && y < self.grid.nb_row {
+                    return Some((
+                        Position::new(x, y),
+                        &self.grid.grid[y * self.grid.nb_col + x],
+                    ));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<T> Grid<T> {
+    /// Private method computing the next state of every cell with `rule` into `cells`, the [Grid] itself being
+    /// left untouched. `cells` is cleared first, its allocation being reused.
+    fn next_states(
+        &self,
+        connectivity: Connectivity,
+        mut rule: impl FnMut(Position, &T, Neighbors<'_, T>) -> T,
+        cells: &mut Vec<T>,
+    ) {
+        cells.clear();
+        cells.extend(self.grid.iter().enumerate().map(|(index, value)| {
+            let pos = self.position(index);
+            let neighbors = Neighbors {
+                grid: self,
+                pos,
+                offsets: connectivity.offsets().iter(),
+            };
+            rule(pos, value, neighbors)
+        }));
+    }
+
+    /// Computes the next generation of the [Grid]: every cell is replaced by the result of `rule`,
+    /// which receives the position and value of the cell along with its neighbours in the current generation.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::{Connectivity, Grid};
+    ///
+    ///    // A blinker, from the Game of Life.
+    ///    let grid: Grid<char> = ".....\n..#..\n..#..\n..#..\n.....".parse().unwrap();
+    ///    let next = grid.step(Connectivity::Eight, |_, c, neighbors| {
+    ///        match (*c, neighbors.filter(|(_, n)| **n == '#').count()) {
+    ///            ('#', 2 | 3) | ('.', 3) => '#',
+    ///            _ => '.',
+    ///        }
+    ///    });
+    ///    assert_eq!(next.display().to_string(), ".....\n.....\n.###.\n.....\n.....\n");
+    /// ```
+    pub fn step(
+        &self,
+        connectivity: Connectivity,
+        rule: impl FnMut(Position, &T, Neighbors<'_, T>) -> T,
+    ) -> Grid<T> {
+        let mut grid = Vec::with_capacity(self.grid.len());
+        self.next_states(connectivity, rule, &mut grid);
+        Grid {
+            grid,
+            nb_row: self.nb_row,
+            nb_col: self.nb_col,
+        }
+    }
+}
+
+/// A space of cells that an [Automaton] can step, like a [Grid] or a [crate::hex::HexMap].
+/// A rule receives the address of a cell, its value and its neighbours, and returns its next value.
+pub trait Topology: Sized {
+    /// Address of a cell, [Position] for a [Grid].
+    type Pos: Copy;
+    /// Value of a cell given to rules, `&T` for a [Grid].
+    type Cell<'a>
+    where
+        Self: 'a;
+    /// Value of a cell returned by rules, `T` for a [Grid].
+    type Next;
+    /// Neighbours of a cell given to rules, [Neighbors] for a [Grid].
+    type Neighbors<'a>
+    where
+        Self: 'a;
+    /// Which cells are neighbours, [Connectivity] for a [Grid].
+    type Neighborhood: Copy;
+    /// Snapshot of every cell, compared by [Automaton::find_cycle].
+    type Snapshot;
+
+    /// Computes the next generation with `rule`. `buffer`, an older generation, may be reused to avoid allocating.
+    fn next_generation(
+        &self,
+        neighborhood: Self::Neighborhood,
+        buffer: Option<Self>,
+        rule: impl FnMut(Self::Pos, Self::Cell<'_>, Self::Neighbors<'_>) -> Self::Next,
+    ) -> Self;
+
+    /// Copies the cell at `pos` into `next`, so that it keeps its current value.
+    fn keep(&self, pos: Self::Pos, next: &mut Self);
+
+    /// Retrieves a snapshot of every cell.
+    fn snapshot(&self) -> Self::Snapshot;
+}
+
+impl<T: Clone> Topology for Grid<T> {
+    type Pos = Position;
+    type Cell<'a>
+        = &'a T
+    where
+        T: 'a;
+    type Next = T;
+    type Neighbors<'a>
+        = Neighbors<'a, T>
+    where
+        T: 'a;
+    type Neighborhood = Connectivity;
+    type Snapshot = Vec<T>;
+
+    fn next_generation(
+        &self,
+        connectivity: Connectivity,
+        buffer: Option<Self>,
+        rule: impl FnMut(Position, &T, Neighbors<'_, T>) -> T,
+    ) -> Self {
+        let mut grid =
+            buffer.map_or_else(|| Vec::with_capacity(self.grid.len()), |buffer| buffer.grid);
+        self.next_states(connectivity, rule, &mut grid);
+        Grid {
+            grid,
+            nb_row: self.nb_row,
+            nb_col: self.nb_col,
+        }
+    }
+
+    fn keep(&self, pos: Position, next: &mut Self) {
+        let index = pos.y() * self.nb_col + pos.x();
+        next.grid[index] = self.grid[index].clone();
+    }
+
+    fn snapshot(&self) -> Vec<T> {
+        self.grid.clone()
+    }
+}
+
+/// A state already seen while running an [Automaton], see [Automaton::find_cycle].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    start: usize,
+    period: usize,
+}
+
+impl Cycle {
+    /// Retrieves the first generation of the cycle.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Retrieves the number of generations after which the state repeats.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+}
+
+/// Runs a cellular automaton over a [Topology], like a [Grid] or a [crate::hex::HexMap], keeping the previous
+/// generation as a buffer so that allocations are reused between generations.
+/// Fixed cells keep their value whatever the rule says, eg. corners that are always on.
+pub struct Automaton<S: Topology> {
+    state: S,
+    buffer: Option<S>,
+    fixed: Vec<S::Pos>,
+    neighborhood: S::Neighborhood,
+    generation: usize,
+}
+
+impl<T: Clone> Automaton<Grid<T>> {
+    /// Fixes the cells at `positions`, which will keep their current value on every generation.
+    /// Error is raised if one of the positions is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::{Automaton, Connectivity, Grid}, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![true, false, false, false], 2)).unwrap();
+    ///    let mut automaton = Automaton::new(grid, Connectivity::Eight).fixed([Position::new(0, 0)]).unwrap();
+    ///    automaton.step(|_, _, _| false);
+    ///    assert_eq!(automaton.state().iter().collect::<Vec<_>>(), vec![&true, &false, &false, &false]);
+    /// ```
+    pub fn fixed(mut self, positions: impl IntoIterator<Item = Position>) -> LibResult<Self> {
+        for pos in positions {
+            if pos.x() >= self.state.nb_col || pos.y() >= self.state.nb_row {
+                return Err(LibError::from(Error::InvalidPosition(pos)));
+            }
+            self.fixed.push(pos);
+        }
+        Ok(self)
+    }
+}
+
+impl<S: Topology> Automaton<S> {
+    /// Creates a new automaton starting from `state`, rules receiving neighbours according to `neighborhood`.
+    pub fn new(state: S, neighborhood: S::Neighborhood) -> Self {
+        Self {
+            state,
+            buffer: None,
+            fixed: Vec::new(),
+            neighborhood,
+            generation: 0,
+        }
+    }
+
+    /// Retrieves the current generation.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Consumes the automaton, retrieving the current generation.
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// Retrieves the number of generations computed so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Computes the next generation with `rule`, see [Grid::step]. Fixed cells are left untouched.
+    pub fn step(&mut self, rule: impl FnMut(S::Pos, S::Cell<'_>, S::Neighbors<'_>) -> S::Next) {
+        let mut next = self
+            .state
+            .next_generation(self.neighborhood, self.buffer.take(), rule);
+        for pos in &self.fixed {
+            self.state.keep(*pos, &mut next);
+        }
+
+        self.buffer = Some(std::mem::replace(&mut self.state, next));
+        self.generation += 1;
+    }
+
+    /// Computes the next `n` generations with `rule`, see [Automaton::step].
+    /// Example
+    /// ```rust
+    ///    use lib::grid::{Automaton, Connectivity, Grid};
+    ///
+    ///    let grid = Grid::try_from((vec![0, 0, 0, 0], 2)).unwrap();
+    ///    let mut automaton = Automaton::new(grid, Connectivity::Four);
+    ///    automaton.run(3, |_, v, neighbors| v + neighbors.count());
+    ///    assert_eq!(automaton.state().iter().collect::<Vec<_>>(), vec![&6, &6, &6, &6]);
+    ///    assert_eq!(automaton.generation(), 3);
+    /// ```
+    pub fn run(
+        &mut self,
+        n: usize,
+        mut rule: impl FnMut(S::Pos, S::Cell<'_>, S::Neighbors<'_>) -> S::Next,
+    ) {
+        for _ in 0..n {
+            self.step(&mut rule);
+        }
+    }
+
+    /// Computes generations with `rule` until a state repeats, then reports the cycle.
+    /// The automaton is left on the first repetition of the state, ie. at generation `start + period`.
+    /// The state of a far generation `g` is then the one of generation `start + (g - start) % period`.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::{Automaton, Connectivity, Grid};
+    ///
+    ///    let grid = Grid::try_from((vec![0, 0, 0], 3)).unwrap();
+    ///    let mut automaton = Automaton::new(grid, Connectivity::Four);
+    ///    let cycle = automaton.find_cycle(|_, v, _| (v + 1) % 4);
+    ///    assert_eq!((cycle.start(), cycle.period()), (0, 4));
+    /// ```
+    pub fn find_cycle(
+        &mut self,
+        mut rule: impl FnMut(S::Pos, S::Cell<'_>, S::Neighbors<'_>) -> S::Next,
+    ) -> Cycle
+    where
+        S::Snapshot: Hash + Eq,
+    {
+        let mut seen = HashMap::new();
+        loop {
+            if let Some(start) = seen.insert(self.state.snapshot(), self.generation) {
+                return Cycle {
+                    start,
+                    period: self.generation - start,
+                };
+            }
+            self.step(&mut rule);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::{Automaton, Connectivity, Error, Grid, Neighbors},
+        position::Position,
+    };
+
+    const LIGHTS: &str = ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..";
+
+    fn life(_: Position, on: &bool, neighbors: Neighbors<'_, bool>) -> bool {
+        matches!(
+            (*on, neighbors.filter(|(_, n)| **n).count()),
+            (true, 2 | 3) | (false, 3)
+        )
+    }
+
+    fn lights() -> anyhow::Result<Grid<bool>> {
+        Ok(Grid::parse_with(LIGHTS, |_, c| {
+            Ok::<_, anyhow::Error>(c == '#')
+        })?)
+    }
+
+    #[test]
+    fn step() -> anyhow::Result<()> {
+        let g = lights()?;
+
+        let next = g.step(Connectivity::Eight, life);
+        assert_eq!(next.iter().filter(|on| **on).count(), 11);
+        // The original Grid is left untouched.
+        assert_eq!(g.iter().filter(|on| **on).count(), 15);
+
+        // Neighbours are given in row-major order, and only inside the Grid.
+        let g = Grid::try_from((vec![0; 6], 3))?;
+        let next = g.step(Connectivity::Four, |pos, _, neighbors| {
+            let neighbors = neighbors.map(|(pos, _)| pos).collect::<Vec<_>>();
+            assert!(neighbors
+                .windows(2)
+                .all(|w| (w[0].y(), w[0].x()) < (w[1].y(), w[1].x())));
+            assert!(neighbors
+                .iter()
+                .all(|n| n.x() < 3 && n.y() < 2 && n != &pos));
+            neighbors.len()
+        });
+        assert_eq!(
+            next.iter().collect::<Vec<_>>(),
+            vec![&2, &3, &2, &2, &3, &2]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn run() -> anyhow::Result<()> {
+        let mut automaton = Automaton::new(lights()?, Connectivity::Eight);
+        automaton.run(4, life);
+        assert_eq!(automaton.generation(), 4);
+        assert_eq!(automaton.state().iter().filter(|on| **on).count(), 4);
+
+        // Corners always on.
+        let mut g = lights()?;
+        let corners = [
+            Position::new(0, 0),
+            Position::new(5, 0),
+            Position::new(0, 5),
+            Position::new(5, 5),
+        ];
+        for corner in &corners {
+            *g.get_mut(corner)? = true;
+        }
+        let mut automaton = Automaton::new(g, Connectivity::Eight).fixed(corners)?;
+        automaton.run(5, life);
+        assert_eq!(automaton.into_state().iter().filter(|on| **on).count(), 17);
+
+        assert!(matches!(
+            Automaton::new(lights()?, Connectivity::Eight).fixed([Position::new(6, 0)]),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_cycle() -> anyhow::Result<()> {
+        // A blinker oscillates right away.
+        let blinker = Grid::parse_with(".....\n..#..\n..#..\n..#..\n.....", |_, c| {
+            Ok::<_, anyhow::Error>(c == '#')
+        })?;
+        let mut automaton = Automaton::new(blinker, Connectivity::Eight);
+        let cycle = automaton.find_cycle(life);
+        assert_eq!((cycle.start(), cycle.period()), (0, 2));
+        assert_eq!(automaton.generation(), 2);
+
+        // The sample settles on a still life.
+        let mut automaton = Automaton::new(lights()?, Connectivity::Eight);
+        let cycle = automaton.find_cycle(life);
+        assert_eq!((cycle.start(), cycle.period()), (4, 1));
+        assert_eq!(automaton.state().iter().filter(|on| **on).count(), 4);
+
+        Ok(())
+    }
+}
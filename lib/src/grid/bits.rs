@@ -0,0 +1,460 @@
+//! Bits module. A boolean [Grid] storing one bit per cell.
+
+use std::fmt::Display;
+
+use crate::{
+    error::{LibError, LibResult},
+    grid::{check_rect, Error, Grid},
+    position::Position,
+};
+
+/// Number of cells stored in a word.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A two dimension boolean grid storing one bit per cell, with word-level rectangle and boolean operations.
+/// Every row starts on a new `u64` word, the unused bits of the last word of a row always being cleared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    words_per_row: usize,
+    nb_row: usize,
+    nb_col: usize,
+}
+
+/// TryFrom implementation to create a BitGrid, like [Grid]. The second parameter is the width, ie. the number of columns.
+impl TryFrom<(Vec<bool>, usize)> for BitGrid {
+    type Error = LibError;
+
+    fn try_from(value: (Vec<bool>, usize)) -> Result<Self, Self::Error> {
+        Ok(Self::from(&Grid::try_from(value)?))
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = Self::new(grid.nb_row(), grid.nb_col());
+        for (pos, _) in grid.enumerate().filter(|(_, b)| **b) {
+            let (index, mask) = bits.bit(&pos);
+            bits.words[index] |= mask;
+        }
+        bits
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(bits: &BitGrid) -> Self {
+        Grid {
+            grid: bits.iter().collect(),
+            nb_row: bits.nb_row,
+            nb_col: bits.nb_col,
+        }
+    }
+}
+
+/// Displays the BitGrid exactly like a `Grid<bool>`.
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Grid::from(self).fmt(f)
+    }
+}
+
+impl BitGrid {
+    /// Creates a new BitGrid of `nb_row` rows and `nb_col` columns, every cell being cleared.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::BitGrid;
+    ///
+    ///    let bits = BitGrid::new(2, 3);
+    ///    assert_eq!((bits.nb_row(), bits.nb_col(), bits.count_ones()), (2, 3, 0));
+    /// ```
+    pub fn new(nb_row: usize, nb_col: usize) -> Self {
+        let words_per_row = nb_col.div_ceil(WORD_BITS);
+        Self {
+            words: vec![0; words_per_row * nb_row],
+            words_per_row,
+            nb_row,
+            nb_col,
+        }
+    }
+
+    /// Retrieves the number of rows of the [BitGrid].
+    pub fn nb_row(&self) -> usize {
+        self.nb_row
+    }
+
+    /// Retrieves the number of columns of the [BitGrid].
+    pub fn nb_col(&self) -> usize {
+        self.nb_col
+    }
+
+    /// Private method retrieving the word index and bit mask of a valid position.
+    fn bit(&self, pos: &Position) -> (usize, u64) {
+        (
+            pos.y() * self.words_per_row + pos.x() / WORD_BITS,
+            1 << (pos.x() % WORD_BITS),
+        )
+    }
+
+    /// Private method checking that `pos` is in the [BitGrid].
+    fn check_position(&self, pos: &Position) -> LibResult<()> {
+        if pos.x() >= self.nb_col || pos.y() >= self.nb_row {
+            Err(LibError::from(Error::InvalidPosition(*pos)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Private method checking that `other` has the same size.
+    fn check_size(&self, other: &BitGrid) -> LibResult<()> {
+        if (self.nb_row, self.nb_col) == (other.nb_row, other.nb_col) {
+            Ok(())
+        } else {
+            Err(LibError::from(Error::SizeMismatch(
+                (self.nb_row, self.nb_col),
+                (other.nb_row, other.nb_col),
+            )))
+        }
+    }
+
+    /// Retrieves the value of the cell at `pos`. Error is raised if `pos` is out of the [BitGrid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::BitGrid, position::Position};
+    ///
+    ///    let bits = BitGrid::try_from((vec![true, false, false, true], 2)).unwrap();
+    ///    assert!(bits.get(&Position::new(1, 1)).unwrap());
+    ///    assert!(!bits.get(&Position::new(0, 1)).unwrap());
+    ///    assert!(bits.get(&Position::new(2, 1)).is_err());
+    /// ```
+    pub fn get(&self, pos: &Position) -> LibResult<bool> {
+        self.check_position(pos)?;
+        let (index, mask) = self.bit(pos);
+        Ok(self.words[index] & mask != 0)
+    }
+
+    /// Sets the cell at `pos` to `value`. Error is raised if `pos` is out of the [BitGrid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::BitGrid, position::Position};
+    ///
+    ///    let mut bits = BitGrid::new(2, 2);
+    ///    bits.set(&Position::new(1, 0), true).unwrap();
+    ///    assert!(bits.get(&Position::new(1, 0)).unwrap());
+    /// ```
+    pub fn set(&mut self, pos: &Position, value: bool) -> LibResult<()> {
+        self.check_position(pos)?;
+        let (index, mask) = self.bit(pos);
+        if value {
+            self.words[index] |= mask;
+        } else {
+            self.words[index] &= !mask;
+        }
+        Ok(())
+    }
+
+    /// Private method applying `op` to every word of the rectangle, along with the mask of the rectangle's bits.
+    fn apply_rect(
+        &mut self,
+        top_left: Position,
+        bottom_right: Position,
+        op: impl Fn(&mut u64, u64),
+    ) -> LibResult<()> {
+        check_rect(self.nb_row, self.nb_col, &top_left, &bottom_right)?;
+
+        let (first, last) = (top_left.x() / WORD_BITS, bottom_right.x() / WORD_BITS);
+        let first_mask = u64::MAX << (top_left.x() % WORD_BITS);
+        let last_mask = u64::MAX >> (WORD_BITS - 1 - bottom_right.x() % WORD_BITS);
+
+        for row in self
+            .words
+            .chunks_exact_mut(self.words_per_row)
+            .take(bottom_right.y() + 1)
+            .skip(top_left.y())
+        {
+            for (index, word) in row.iter_mut().enumerate().take(last + 1).skip(first) {
+                let mut mask = u64::MAX;
+                if index == first {
+                    mask &= first_mask;
+                }
+                if index == last {
+                    mask &= last_mask;
+                }
+                op(word, mask);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets every cell of the rectangle defined by `top_left` and `bottom_right`, both included.
+    /// Error is raised if the rectangle isn't valid, like [Grid::iter_rect].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::BitGrid, position::Position};
+    ///
+    ///    let mut bits = BitGrid::new(100, 100);
+    ///    bits.set_rect(Position::new(10, 10), Position::new(89, 19)).unwrap();
+    ///    assert_eq!(bits.count_ones(), 800);
+    /// ```
+    pub fn set_rect(&mut self, top_left: Position, bottom_right: Position) -> LibResult<()> {
+        self.apply_rect(top_left, bottom_right, |word, mask| *word |= mask)
+    }
+
+    /// Clears every cell of the rectangle defined by `top_left` and `bottom_right`, both included.
+    /// Error is raised if the rectangle isn't valid, like [Grid::iter_rect].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::BitGrid, position::Position};
+    ///
+    ///    let mut bits = BitGrid::new(100, 100);
+    ///    bits.invert();
+    ///    bits.clear_rect(Position::new(0, 0), Position::new(99, 49)).unwrap();
+    ///    assert_eq!(bits.count_ones(), 5000);
+    /// ```
+    pub fn clear_rect(&mut self, top_left: Position, bottom_right: Position) -> LibResult<()> {
+        self.apply_rect(top_left, bottom_right, |word, mask| *word &= !mask)
+    }
+
+    /// Toggles every cell of the rectangle defined by `top_left` and `bottom_right`, both included.
+    /// Error is raised if the rectangle isn't valid, like [Grid::iter_rect].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::BitGrid, position::Position};
+    ///
+    ///    let mut bits = BitGrid::new(3, 3);
+    ///    bits.set_rect(Position::new(0, 0), Position::new(1, 1)).unwrap();
+    ///    bits.toggle_rect(Position::new(1, 1), Position::new(2, 2)).unwrap();
+    ///    assert_eq!(bits.count_ones(), 6);
+    /// ```
+    pub fn toggle_rect(&mut self, top_left: Position, bottom_right: Position) -> LibResult<()> {
+        self.apply_rect(top_left, bottom_right, |word, mask| *word ^= mask)
+    }
+
+    /// Counts the cells that are set.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::BitGrid;
+    ///
+    ///    let bits = BitGrid::try_from((vec![true, false, true, true], 2)).unwrap();
+    ///    assert_eq!(bits.count_ones(), 3);
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Toggles every cell of the [BitGrid].
+    /// Example
+    /// ```rust
+    ///    use lib::grid::BitGrid;
+    ///
+    ///    let mut bits = BitGrid::try_from((vec![true, false, true, true], 2)).unwrap();
+    ///    bits.invert();
+    ///    assert_eq!(bits.iter().collect::<Vec<_>>(), vec![false, true, false, false]);
+    /// ```
+    pub fn invert(&mut self) {
+        let last_mask = match self.nb_col % WORD_BITS {
+            0 => u64::MAX,
+            used => (1 << used) - 1,
+        };
+        for row in self.words.chunks_exact_mut(self.words_per_row.max(1)) {
+            row.iter_mut().for_each(|word| *word = !*word);
+            if let Some(last) = row.last_mut() {
+                *last &= last_mask;
+            }
+        }
+    }
+
+    /// Private method combining every word with the matching word of `other`. Error is raised if sizes differ.
+    fn combine(&mut self, other: &BitGrid, op: impl Fn(u64, u64) -> u64) -> LibResult<()> {
+        self.check_size(other)?;
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word = op(*word, *other);
+        }
+        Ok(())
+    }
+
+    /// Keeps the cells set in both grids. Error is raised if both grids don't have the same size.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::BitGrid;
+    ///
+    ///    let mut bits = BitGrid::try_from((vec![true, true, false, false], 2)).unwrap();
+    ///    let other = BitGrid::try_from((vec![true, false, true, false], 2)).unwrap();
+    ///    bits.and(&other).unwrap();
+    ///    assert_eq!(bits.iter().collect::<Vec<_>>(), vec![true, false, false, false]);
+    ///    assert!(bits.and(&BitGrid::new(4, 1)).is_err());
+    /// ```
+    pub fn and(&mut self, other: &BitGrid) -> LibResult<()> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Sets the cells set in any of the grids. Error is raised if both grids don't have the same size.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::BitGrid;
+    ///
+    ///    let mut bits = BitGrid::try_from((vec![true, true, false, false], 2)).unwrap();
+    ///    let other = BitGrid::try_from((vec![true, false, true, false], 2)).unwrap();
+    ///    bits.or(&other).unwrap();
+    ///    assert_eq!(bits.iter().collect::<Vec<_>>(), vec![true, true, true, false]);
+    /// ```
+    pub fn or(&mut self, other: &BitGrid) -> LibResult<()> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Keeps the cells set in exactly one of the grids. Error is raised if both grids don't have the same size.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::BitGrid;
+    ///
+    ///    let mut bits = BitGrid::try_from((vec![true, true, false, false], 2)).unwrap();
+    ///    let other = BitGrid::try_from((vec![true, false, true, false], 2)).unwrap();
+    ///    bits.xor(&other).unwrap();
+    ///    assert_eq!(bits.iter().collect::<Vec<_>>(), vec![false, true, true, false]);
+    /// ```
+    pub fn xor(&mut self, other: &BitGrid) -> LibResult<()> {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    /// Yields an [Iterator] over the values of the whole [BitGrid]. The data is traversed row by row.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.nb_row).flat_map(move |y| {
+            (0..self.nb_col).map(move |x| {
+                let (index, mask) = self.bit(&Position::new(x, y));
+                self.words[index] & mask != 0
+            })
+        })
+    }
+
+    /// Yields an [Iterator] over the values of the rectangle defined by `top_left` and `bottom_right`, row by row.
+    /// Error is raised if the rectangle isn't valid, like [Grid::iter_rect].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::BitGrid, position::Position};
+    ///
+    ///    let bits = BitGrid::try_from((vec![
+    ///            true, false, true, false, true, false, true, false, true
+    ///        ], 3)).unwrap();
+    ///    assert_eq!(bits.iter_rect(Position::new(1, 1), Position::new(2, 2)).unwrap().collect::<Vec<_>>(), vec![
+    ///            true, false, false, true
+    ///        ]);
+    /// ```
+    pub fn iter_rect(
+        &self,
+        top_left: Position,
+        bottom_right: Position,
+    ) -> LibResult<impl Iterator<Item = bool> + '_> {
+        check_rect(self.nb_row, self.nb_col, &top_left, &bottom_right)?;
+
+        Ok(
+            Grid::<bool>::rect_positions(top_left, bottom_right).map(move |pos| {
+                let (index, mask) = self.bit(&pos);
+                self.words[index] & mask != 0
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::{BitGrid, Error, Grid},
+        position::Position,
+    };
+
+    #[test]
+    fn rect() -> anyhow::Result<()> {
+        // Rectangles spanning several words, and rows not ending on a word boundary.
+        let mut bits = BitGrid::new(5, 150);
+        let mut grid = Grid::try_from((vec![false; 5 * 150], 150))?;
+        let rects = [
+            (Position::new(3, 0), Position::new(140, 2), 0),
+            (Position::new(60, 1), Position::new(70, 4), 1),
+            (Position::new(0, 0), Position::new(149, 4), 2),
+            (Position::new(64, 3), Position::new(127, 3), 1),
+            (Position::new(63, 2), Position::new(63, 2), 0),
+        ];
+        for (top_left, bottom_right, op) in rects {
+            let func = match op {
+                0 => |b: &mut bool| *b = !*b,
+                1 => |b: &mut bool| *b = true,
+                _ => |b: &mut bool| *b = false,
+            };
+            grid.iter_rect_mut(top_left, bottom_right)?.for_each(func);
+            match op {
+                0 => bits.toggle_rect(top_left, bottom_right)?,
+                1 => bits.set_rect(top_left, bottom_right)?,
+                _ => bits.clear_rect(top_left, bottom_right)?,
+            }
+            assert_eq!(
+                bits.iter().collect::<Vec<_>>(),
+                grid.iter().copied().collect::<Vec<_>>()
+            );
+            assert_eq!(bits.count_ones(), grid.iter().filter(|b| **b).count());
+        }
+
+        assert!(matches!(
+            bits.set_rect(Position::new(0, 0), Position::new(150, 0)),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+        assert!(matches!(
+            bits.toggle_rect(Position::new(2, 0), Position::new(1, 0)),
+            Err(LibError::Grid(Error::InvalidRectangle(_, _)))
+        ));
+        assert!(bits
+            .iter_rect(Position::new(0, 0), Position::new(0, 5))
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ops() -> anyhow::Result<()> {
+        let mut bits = BitGrid::new(2, 70);
+        bits.invert();
+        // Padding bits stay cleared.
+        assert_eq!(bits.count_ones(), 140);
+
+        let mut other = BitGrid::new(2, 70);
+        other.set_rect(Position::new(0, 0), Position::new(9, 1))?;
+        bits.xor(&other)?;
+        assert_eq!(bits.count_ones(), 120);
+        bits.or(&other)?;
+        assert_eq!(bits.count_ones(), 140);
+        bits.and(&other)?;
+        assert_eq!(bits, other);
+
+        assert!(matches!(
+            bits.or(&BitGrid::new(70, 2)),
+            Err(LibError::Grid(Error::SizeMismatch((2, 70), (70, 2))))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_width() {
+        let mut bits = BitGrid::new(3, 0);
+        bits.invert();
+        assert_eq!(bits.count_ones(), 0);
+        assert_eq!((bits.nb_row(), bits.nb_col()), (3, 0));
+    }
+
+    #[test]
+    fn display() -> anyhow::Result<()> {
+        let cells = vec![true, false, false, true, true, false];
+        let grid = Grid::try_from((cells.clone(), 3))?;
+        let bits = BitGrid::try_from((cells, 3))?;
+        assert_eq!(bits.to_string(), grid.to_string());
+        assert_eq!(
+            Grid::from(&bits).iter().collect::<Vec<_>>(),
+            grid.iter().collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+//! Growing module. A dense grid indexed by [NPosition], reallocating by chunks when written outside of its extent.
+
+use std::fmt::Display;
+
+use crate::{
+    grid::{Grid, GridView, NEIGHBORS4, NEIGHBORS8},
+    nposition::NPosition,
+    position::Position,
+};
+
+/// Minimum number of rows or columns added when a [GrowingGrid] reallocates.
+const CHUNK: usize = 16;
+
+/// A dense two dimension grid without bounds, indexed by [NPosition]. Every cell is `fill` until written.
+/// Writing outside of the allocated extent reallocates the grid, at least doubling it on the side of the write,
+/// so that the origin shift stays hidden. [GrowingGrid::bounds] only covers the cells written so far.
+#[derive(Debug)]
+pub struct GrowingGrid<T> {
+    grid: Grid<T>,
+    origin: NPosition,
+    bounds: Option<(NPosition, NPosition)>,
+    fill: T,
+}
+
+/// Renders the bounding box of the written cells, see [GrowingGrid::as_view].
+impl<T: Display> Display for GrowingGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_view().display().fmt(f)
+    }
+}
+
+impl<T> GrowingGrid<T> {
+    /// Creates an empty GrowingGrid, every cell being `fill`. Nothing is allocated until the first write.
+    pub fn new(fill: T) -> Self {
+        Self {
+            grid: Grid {
+                grid: Vec::new(),
+                nb_row: 0,
+                nb_col: 0,
+            },
+            origin: NPosition::default(),
+            bounds: None,
+            fill,
+        }
+    }
+
+    /// Creates a GrowingGrid from a [Grid], its top left corner being at `(0, 0)`. Every cell of `grid` is considered written.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::{Grid, GrowingGrid}, nposition::NPosition};
+    ///
+    ///    let grid: Grid<char> = "#.\n.#".parse().unwrap();
+    ///    let mut growing = GrowingGrid::from_grid(grid, '.');
+    ///    growing.set(NPosition::new(-1, 2), '#');
+    ///    assert_eq!(growing.bounds(), Some((NPosition::new(-1, 0), NPosition::new(1, 2))));
+    ///    assert_eq!(growing.to_string(), ".#.\n..#\n#..\n");
+    /// ```
+    pub fn from_grid(grid: Grid<T>, fill: T) -> Self {
+        let bounds = (grid.nb_row > 0 && grid.nb_col > 0).then(|| {
+            (
+                NPosition::default(),
+                NPosition::new(grid.nb_col as isize - 1, grid.nb_row as isize - 1),
+            )
+        });
+
+        Self {
+            grid,
+            origin: NPosition::default(),
+            bounds,
+            fill,
+        }
+    }
+
+    /// Retrieves the minimum and maximum coordinates of the cells written so far, `None` if none was.
+    pub fn bounds(&self) -> Option<(NPosition, NPosition)> {
+        self.bounds
+    }
+
+    /// Private method retrieving the index of `pos` in the allocated grid, if inside of it.
+    fn index(&self, pos: &NPosition) -> Option<usize> {
+        let (x, y) = pos
+            .relative_to(&self.origin)
+            .map(|pos| (pos.x(), pos.y()))
+            .ok()?;
+
+        (x < self.grid.nb_col && y < self.grid.nb_row).then_some(y * self.grid.nb_col + x)
+    }
+
+    /// Retrieves a reference over the cell at `pos`, `fill` if it was never written.
+    pub fn get(&self, pos: &NPosition) -> &T {
+        self.index(pos)
+            .map_or(&self.fill, |index| &self.grid.grid[index])
+    }
+
+    /// Yields an [Iterator] of positions and references over the cells at `offsets` of `pos`,
+    /// in the order of `offsets`, cells never written being `fill`. An offset is a `(dx, dy)` tuple.
+    pub fn neighbors_with_positions<'a>(
+        &'a self,
+        pos: &NPosition,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (NPosition, &'a T)> {
+        let pos = *pos;
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let neighbor = NPosition::new(pos.x().checked_add(dx)?, pos.y().checked_add(dy)?);
+            Some((neighbor, self.get(&neighbor)))
+        })
+    }
+
+    /// Yields an [Iterator] of positions and references over the orthogonal neighbours of `pos`, in row-major order.
+    pub fn neighbors4(&self, pos: &NPosition) -> impl Iterator<Item = (NPosition, &T)> {
+        self.neighbors_with_positions(pos, &NEIGHBORS4)
+    }
+
+    /// Yields an [Iterator] of positions and references over the surrounding neighbours of `pos`,
+    /// diagonals included, in row-major order.
+    pub fn neighbors8(&self, pos: &NPosition) -> impl Iterator<Item = (NPosition, &T)> {
+        self.neighbors_with_positions(pos, &NEIGHBORS8)
+    }
+
+    /// Creates a [GridView] over the bounding box of the written cells. Its `(0, 0)` cell is at the minimum of
+    /// [GrowingGrid::bounds]. The view is empty if nothing was written.
+    pub fn as_view(&self) -> GridView<'_, T> {
+        match self.bounds {
+            None => self.grid.as_view(),
+            Some((min, max)) => {
+                let corner = |pos: NPosition| {
+                    Position::new(
+                        pos.x().abs_diff(self.origin.x()),
+                        pos.y().abs_diff(self.origin.y()),
+                    )
+                };
+                self.grid
+                    .view(corner(min), corner(max))
+                    .expect("written cells are allocated")
+            }
+        }
+    }
+
+    /// Yields an [Iterator] of positions and references over the bounding box of the written cells, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (NPosition, &T)> {
+        let min = self.bounds.map_or(NPosition::default(), |(min, _)| min);
+        self.as_view().enumerate().map(move |(pos, value)| {
+            let pos = pos
+                .offset_by(&min)
+                .expect("cells of the view stay within the bounds");
+            (pos, value)
+        })
+    }
+}
+
+impl<T: Clone> GrowingGrid<T> {
+    /// Private method reallocating the grid so that it contains `pos`, then retrieving the index of `pos`.
+    fn reserve(&mut self, pos: &NPosition) -> usize {
+        if let Some(index) = self.index(pos) {
+            return index;
+        }
+
+        let (min, max) = if self.grid.grid.is_empty() {
+            let half = CHUNK as isize / 2;
+            (
+                NPosition::new(pos.x().saturating_sub(half), pos.y().saturating_sub(half)),
+                NPosition::new(pos.x().saturating_add(half), pos.y().saturating_add(half)),
+            )
+        } else {
+            let (nb_row, nb_col) = (self.grid.nb_row as isize, self.grid.nb_col as isize);
+            let grow = |value: isize, min: isize, max: isize, size: isize| {
+                let step = size.max(CHUNK as isize);
+                if value < min {
+                    (value.saturating_sub(step), max)
+                } else if value > max {
+                    (min, value.saturating_add(step))
+                } else {
+                    (min, max)
+                }
+            };
+            let (min_x, max_x) = grow(
+                pos.x(),
+                self.origin.x(),
+                self.origin.x() + nb_col - 1,
+                nb_col,
+            );
+            let (min_y, max_y) = grow(
+                pos.y(),
+                self.origin.y(),
+                self.origin.y() + nb_row - 1,
+                nb_row,
+            );
+            (NPosition::new(min_x, min_y), NPosition::new(max_x, max_y))
+        };
+
+        let nb_row = max.y().abs_diff(min.y()) + 1;
+        let nb_col = max.x().abs_diff(min.x()) + 1;
+        let (dx, dy) = (
+            self.origin.x().abs_diff(min.x()),
+            self.origin.y().abs_diff(min.y()),
+        );
+        let mut grid = vec![self.fill.clone(); nb_row * nb_col];
+        let old_nb_col = self.grid.nb_col;
+        for (index, value) in std::mem::take(&mut self.grid.grid).into_iter().enumerate() {
+            let (x, y) = (index % old_nb_col, index / old_nb_col);
+            grid[(y + dy) * nb_col + x + dx] = value;
+        }
+
+        self.grid = Grid {
+            grid,
+            nb_row,
+            nb_col,
+        };
+        self.origin = min;
+        self.index(pos)
+            .expect("reallocated grid contains the position")
+    }
+
+    /// Retrieves a mutable reference over the cell at `pos`, growing the grid if needed.
+    /// The cell is considered written, and included in [GrowingGrid::bounds].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::GrowingGrid, nposition::NPosition};
+    ///
+    ///    let mut grid = GrowingGrid::new(0);
+    ///    *grid.get_mut(NPosition::new(-100, 40)) += 3;
+    ///    *grid.get_mut(NPosition::new(-100, 40)) += 4;
+    ///    assert_eq!(grid.get(&NPosition::new(-100, 40)), &7);
+    ///    assert_eq!(grid.get(&NPosition::new(1000, 1000)), &0);
+    ///    assert_eq!(grid.bounds(), Some((NPosition::new(-100, 40), NPosition::new(-100, 40))));
+    /// ```
+    pub fn get_mut(&mut self, pos: NPosition) -> &mut T {
+        let index = self.reserve(&pos);
+        self.bounds = Some(match self.bounds {
+            None => (pos, pos),
+            Some((min, max)) => (
+                NPosition::new(min.x().min(pos.x()), min.y().min(pos.y())),
+                NPosition::new(max.x().max(pos.x()), max.y().max(pos.y())),
+            ),
+        });
+
+        &mut self.grid.grid[index]
+    }
+
+    /// Writes `value` at `pos`, growing the grid if needed, and returns the previous value of the cell.
+    pub fn set(&mut self, pos: NPosition, value: T) -> T {
+        std::mem::replace(self.get_mut(pos), value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::{grid::GrowingGrid, nposition::NPosition};
+
+    #[test]
+    fn growth() {
+        let mut grid = GrowingGrid::new('.');
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.as_view().nb_row(), 0);
+        assert_eq!(grid.to_string(), "");
+
+        // Spiral outwards, in every direction, compared with a HashMap.
+        let mut expected = HashMap::new();
+        let (mut x, mut y) = (0isize, 0isize);
+        for step in 0..200isize {
+            match step % 4 {
+                0 => x += step,
+                1 => y += step,
+                2 => x -= step,
+                _ => y -= step,
+            }
+            let value = char::from(b'a' + (step % 26) as u8);
+            grid.set(NPosition::new(x, y), value);
+            expected.insert(NPosition::new(x, y), value);
+        }
+        for (pos, value) in &expected {
+            assert_eq!(grid.get(pos), value);
+        }
+        assert_eq!(
+            grid.enumerate().filter(|(_, c)| **c != '.').count(),
+            expected.len()
+        );
+
+        let (min, max) = grid.bounds().unwrap();
+        assert_eq!(min.x(), expected.keys().map(|p| p.x()).min().unwrap());
+        assert_eq!(max.y(), expected.keys().map(|p| p.y()).max().unwrap());
+        assert_eq!(grid.get(&NPosition::new(min.x() - 1, 0)), &'.');
+        // Far from a negative origin, the offset overflows.
+        assert_eq!(grid.get(&NPosition::new(isize::MAX, isize::MIN)), &'.');
+        assert_eq!(
+            grid.enumerate().next(),
+            Some((min, grid.get(&min))),
+            "first cell of the bounding box"
+        );
+    }
+
+    #[test]
+    fn display() {
+        let mut grid = GrowingGrid::new('.');
+        grid.set(NPosition::new(0, 0), 'o');
+        grid.set(NPosition::new(-2, -1), '#');
+        assert_eq!(grid.set(NPosition::new(1, 1), '#'), '.');
+        assert_eq!(grid.to_string(), "#...\n..o.\n...#\n");
+        assert_eq!(
+            grid.neighbors8(&NPosition::new(0, 0))
+                .filter(|(_, c)| **c == '#')
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>(),
+            vec![NPosition::new(1, 1)]
+        );
+        assert_eq!(grid.neighbors4(&NPosition::new(-2, 0)).count(), 4);
+    }
+}
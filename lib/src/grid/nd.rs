@@ -0,0 +1,371 @@
+//! ND module. Dense grids of any number of dimensions, eg. for voxel puzzles.
+
+use std::collections::VecDeque;
+
+use crate::{
+    error::{LibError, LibResult},
+    grid::{Error, Grid},
+};
+
+/// A dense grid of `D` dimensions. A position is an array of `D` coordinates, the first axis being the one
+/// stored contiguously, like `x` for a [Grid]. Positions are checked like for a [Grid].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridN<T, const D: usize> {
+    cells: Vec<T>,
+    dims: [usize; D],
+}
+
+/// TryFrom implementation to create a GridN from its cells and its size along every axis.
+impl<T, const D: usize> TryFrom<(Vec<T>, [usize; D])> for GridN<T, D> {
+    type Error = LibError;
+
+    fn try_from(value: (Vec<T>, [usize; D])) -> Result<Self, Self::Error> {
+        let (cells, dims) = value;
+        if cells.len() == dims.iter().product() {
+            Ok(Self { cells, dims })
+        } else {
+            Err(LibError::from(Error::TryFrom))
+        }
+    }
+}
+
+/// The first axis is `x`, ie. the columns, and the second one `y`, ie. the rows.
+impl<T> From<Grid<T>> for GridN<T, 2> {
+    fn from(grid: Grid<T>) -> Self {
+        Self {
+            cells: grid.grid,
+            dims: [grid.nb_col, grid.nb_row],
+        }
+    }
+}
+
+impl<T> From<GridN<T, 2>> for Grid<T> {
+    fn from(grid: GridN<T, 2>) -> Self {
+        Grid {
+            grid: grid.cells,
+            nb_row: grid.dims[1],
+            nb_col: grid.dims[0],
+        }
+    }
+}
+
+impl<T, const D: usize> GridN<T, D> {
+    /// Creates a new GridN of size `dims`, every cell being `value`.
+    pub fn new(dims: [usize; D], value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; dims.iter().product()],
+            dims,
+        }
+    }
+
+    /// Retrieves the size of the GridN along every axis.
+    pub fn dims(&self) -> [usize; D] {
+        self.dims
+    }
+
+    /// Retrieves the number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Tells if the GridN has no cell.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Private method retrieving the index of `pos`, if inside of the GridN.
+    fn index(&self, pos: &[usize; D]) -> Option<usize> {
+        pos.iter()
+            .zip(self.dims)
+            .rev()
+            .try_fold(0, |index, (&coord, dim)| {
+                (coord < dim).then_some(index * dim + coord)
+            })
+    }
+
+    /// Private method retrieving the position of the cell stored at `index`.
+    fn position(&self, mut index: usize) -> [usize; D] {
+        let mut pos = [0; D];
+        for (coord, dim) in pos.iter_mut().zip(self.dims) {
+            *coord = index % dim;
+            index /= dim;
+        }
+        pos
+    }
+
+    /// Retrieves a reference over the cell at `pos`. Error is raised if `pos` is outside of the GridN.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::GridN;
+    ///
+    ///    let grid = GridN::try_from(((0..24).collect::<Vec<_>>(), [2, 3, 4])).unwrap();
+    ///    assert_eq!(grid.get(&[1, 2, 3]).unwrap(), &23);
+    ///    assert_eq!(grid.get(&[1, 0, 1]).unwrap(), &7);
+    ///    assert!(grid.get(&[2, 0, 0]).is_err());
+    /// ```
+    pub fn get(&self, pos: &[usize; D]) -> LibResult<&T> {
+        self.index(pos)
+            .map(|index| &self.cells[index])
+            .ok_or_else(|| LibError::from(Error::InvalidCoordinates(pos.to_vec())))
+    }
+
+    /// Retrieves a mutable reference over the cell at `pos`. Error is raised if `pos` is outside of the GridN.
+    pub fn get_mut(&mut self, pos: &[usize; D]) -> LibResult<&mut T> {
+        match self.index(pos) {
+            Some(index) => Ok(&mut self.cells[index]),
+            None => Err(LibError::from(Error::InvalidCoordinates(pos.to_vec()))),
+        }
+    }
+
+    /// Yields an [Iterator] of references over every cell, the first axis varying the fastest.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Yields an [Iterator] of mutable references over every cell, the first axis varying the fastest.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Yields an [Iterator] of positions and references over every cell, the first axis varying the fastest.
+    pub fn enumerate(&self) -> impl Iterator<Item = ([usize; D], &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, v)| (self.position(index), v))
+    }
+
+    /// Yields an [Iterator] of positions and references over the neighbours of `pos` which differ along at most
+    /// `max_axes` axes, in the same order as [GridN::enumerate]. In 3D, `max_axes` being 1, 2 and 3 respectively
+    /// gives the 6 face, 18 face or edge, and 26 surrounding neighbours; in 2D, 1 and 2 match [Grid::neighbors4]
+    /// and [Grid::neighbors8]. Neighbours outside of the GridN are skipped. Error is raised if `pos` is outside of it.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::GridN;
+    ///
+    ///    let grid = GridN::new([3, 3, 3], 0);
+    ///    let counts = (1..=3).map(|max_axes| grid.neighbors(&[1, 1, 1], max_axes).unwrap().count()).collect::<Vec<_>>();
+    ///    assert_eq!(counts, vec![6, 18, 26]);
+    ///    assert_eq!(grid.neighbors(&[0, 0, 0], 3).unwrap().count(), 7);
+    /// ```
+    pub fn neighbors(
+        &self,
+        pos: &[usize; D],
+        max_axes: usize,
+    ) -> LibResult<impl Iterator<Item = ([usize; D], &T)>> {
+        if self.index(pos).is_none() {
+            return Err(LibError::from(Error::InvalidCoordinates(pos.to_vec())));
+        }
+
+        let pos = *pos;
+        Ok(Self::offsets(max_axes).filter_map(move |offset| {
+            let mut neighbor = pos;
+            for (coord, delta) in neighbor.iter_mut().zip(offset) {
+                *coord = coord.checked_add_signed(delta)?;
+            }
+            self.index(&neighbor)
+                .map(|index| (neighbor, &self.cells[index]))
+        }))
+    }
+
+    /// Private function yielding every non-zero offset changing at most `max_axes` coordinates, by -1, 0 or 1,
+    /// the first axis varying the fastest.
+    fn offsets(max_axes: usize) -> impl Iterator<Item = [isize; D]> {
+        (0..3usize.pow(D as u32))
+            .map(|mut n| {
+                let mut offset = [0; D];
+                for delta in offset.iter_mut() {
+                    *delta = (n % 3) as isize - 1;
+                    n /= 3;
+                }
+                offset
+            })
+            .filter(move |offset| {
+                let changed = offset.iter().filter(|delta| **delta != 0).count();
+                changed > 0 && changed <= max_axes
+            })
+    }
+
+    /// Yields an [Iterator] of positions and references over the cells whose coordinate along `axis` is `index`,
+    /// in the same order as [GridN::enumerate]. Error is raised if `axis` or `index` don't exist.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::GridN;
+    ///
+    ///    let grid = GridN::try_from(((0..8).collect::<Vec<_>>(), [2, 2, 2])).unwrap();
+    ///    assert_eq!(grid.slice(2, 1).unwrap().map(|(_, v)| *v).collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+    ///    assert_eq!(grid.slice(0, 0).unwrap().map(|(_, v)| *v).collect::<Vec<_>>(), vec![0, 2, 4, 6]);
+    ///    assert!(grid.slice(3, 0).is_err());
+    /// ```
+    pub fn slice(
+        &self,
+        axis: usize,
+        index: usize,
+    ) -> LibResult<impl Iterator<Item = ([usize; D], &T)>> {
+        match self.dims.get(axis) {
+            None => Err(LibError::from(Error::InvalidAxis(axis))),
+            Some(dim) if index >= *dim => Err(LibError::from(Error::InvalidSlice(axis, index))),
+            Some(_) => Ok(self.enumerate().filter(move |(pos, _)| pos[axis] == index)),
+        }
+    }
+
+    /// Finds the exterior of the GridN: every open cell, according to `is_open`, connected to the outside through
+    /// open cells, moving along one axis at a time. Enclosed pockets aren't part of it.
+    pub fn exterior(&self, is_open: impl Fn(&T) -> bool) -> GridN<bool, D> {
+        let mut exterior = GridN::new(self.dims, false);
+        let mut queue = VecDeque::new();
+
+        for (index, cell) in self.cells.iter().enumerate() {
+            let pos = self.position(index);
+            let on_border = pos
+                .iter()
+                .zip(self.dims)
+                .any(|(&coord, dim)| coord == 0 || coord + 1 == dim);
+            if on_border && is_open(cell) {
+                exterior.cells[index] = true;
+                queue.push_back(pos);
+            }
+        }
+
+        while let Some(pos) = queue.pop_front() {
+            for (neighbor, cell) in self.neighbors(&pos, 1).expect("queued positions are valid") {
+                let index = self.index(&neighbor).expect("neighbours are valid");
+                if !exterior.cells[index] && is_open(cell) {
+                    exterior.cells[index] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        exterior
+    }
+}
+
+impl<T: Clone> GridN<T, 3> {
+    /// Copies the cells whose coordinate along `axis` is `index` into a [Grid], the lowest remaining axis
+    /// giving the columns and the highest one the rows. Error is raised if `axis` or `index` don't exist.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::GridN;
+    ///
+    ///    let grid = GridN::try_from(((0..12).collect::<Vec<_>>(), [2, 3, 2])).unwrap();
+    ///    let slice = grid.slice_grid(1, 2).unwrap();
+    ///    assert_eq!((slice.nb_row(), slice.nb_col()), (2, 2));
+    ///    assert_eq!(slice.iter().collect::<Vec<_>>(), vec![&4, &5, &10, &11]);
+    /// ```
+    pub fn slice_grid(&self, axis: usize, index: usize) -> LibResult<Grid<T>> {
+        let grid = self.slice(axis, index)?.map(|(_, v)| v.clone()).collect();
+        let mut dims = self.dims.iter().enumerate().filter(|(i, _)| *i != axis);
+        let (_, &nb_col) = dims.next().expect("3 dimensions");
+        let (_, &nb_row) = dims.next().expect("3 dimensions");
+
+        Ok(Grid {
+            grid,
+            nb_row,
+            nb_col,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::{Error, Grid, GridN},
+        position::Position,
+    };
+
+    const DROPLET: [[usize; 3]; 13] = [
+        [2, 2, 2],
+        [1, 2, 2],
+        [3, 2, 2],
+        [2, 1, 2],
+        [2, 3, 2],
+        [2, 2, 1],
+        [2, 2, 3],
+        [2, 2, 4],
+        [2, 2, 6],
+        [1, 2, 5],
+        [3, 2, 5],
+        [2, 1, 5],
+        [2, 3, 5],
+    ];
+
+    #[test]
+    fn lava_droplet() -> anyhow::Result<()> {
+        let mut lava = GridN::new([4, 4, 7], false);
+        for cube in DROPLET {
+            *lava.get_mut(&cube)? = true;
+        }
+
+        // Faces outside of the grid are exposed too.
+        let faces = |pos: &[usize; 3], exposed: &dyn Fn(&[usize; 3], &bool) -> bool| {
+            let neighbors = lava.neighbors(pos, 1).unwrap().collect::<Vec<_>>();
+            6 - neighbors.len()
+                + neighbors
+                    .into_iter()
+                    .filter(|(pos, cell)| exposed(pos, cell))
+                    .count()
+        };
+
+        let surface: usize = DROPLET
+            .iter()
+            .map(|cube| faces(cube, &|_, cell| !cell))
+            .sum();
+        assert_eq!(surface, 64);
+
+        let exterior = lava.exterior(|cell| !cell);
+        assert!(!*exterior.get(&[2, 2, 5])?);
+        assert!(*exterior.get(&[0, 0, 0])?);
+        let exterior_surface: usize = DROPLET
+            .iter()
+            .map(|cube| faces(cube, &|pos, _| *exterior.get(pos).unwrap()))
+            .sum();
+        assert_eq!(exterior_surface, 58);
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors_and_conversions() -> anyhow::Result<()> {
+        assert!(matches!(
+            GridN::try_from((vec![0; 5], [2, 3])),
+            Err(LibError::Grid(Error::TryFrom))
+        ));
+
+        let mut grid = GridN::new([2, 2, 2, 2], 0u8);
+        assert_eq!(grid.len(), 16);
+        assert_eq!(grid.neighbors(&[0, 1, 0, 1], 4)?.count(), 15);
+        assert!(matches!(
+            grid.get_mut(&[0, 0, 2, 0]),
+            Err(LibError::Grid(Error::InvalidCoordinates(pos))) if pos == vec![0, 0, 2, 0]
+        ));
+        assert!(matches!(
+            grid.slice(1, 2).map(|slice| slice.count()),
+            Err(LibError::Grid(Error::InvalidSlice(1, 2)))
+        ));
+        assert!(grid.neighbors(&[0, 0, 0, 2], 1).is_err());
+
+        // Same layout and neighbours order as a Grid.
+        let flat = Grid::try_from(((0..12).collect::<Vec<_>>(), 4))?;
+        let expected = flat
+            .neighbors8(&Position::new(1, 1))?
+            .map(|(_, v)| *v)
+            .collect::<Vec<_>>();
+        let grid = GridN::from(flat);
+        assert_eq!(grid.dims(), [4, 3]);
+        assert_eq!(
+            grid.neighbors(&[1, 1], 2)?
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>(),
+            expected
+        );
+        let flat = Grid::from(grid);
+        assert_eq!((flat.nb_row(), flat.nb_col()), (3, 4));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,414 @@
+//! Path module. Breadth-first, Dijkstra and A* searches over a [Grid].
+//! Moves are made between orthogonal neighbours, and the caller decides which moves are allowed
+//! and how much they cost.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+use crate::{error::LibResult, grid::Grid, position::Position};
+
+/// A path found in a [Grid], from one of the starts to the goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    distance: usize,
+    positions: Vec<Position>,
+}
+
+impl Path {
+    /// Retrieves the total distance of the path, ie. the number of moves for a breadth-first search,
+    /// or the sum of the moves cost otherwise.
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+
+    /// Retrieves every position of the path, from the start to the goal.
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+}
+
+impl<T> Grid<T> {
+    /// Private method converting `starts` to indexes. Error is raised if one of them is out of the [Grid].
+    fn start_indexes(&self, starts: impl IntoIterator<Item = Position>) -> LibResult<Vec<usize>> {
+        starts
+            .into_iter()
+            .map(|pos| {
+                self.get(&pos)?;
+                Ok(pos.y() * self.nb_col + pos.x())
+            })
+            .collect()
+    }
+
+    /// Private method rebuilding the path ending at `index` from the predecessors of every cell.
+    fn rebuild_path(&self, mut index: usize, previous: &[Option<usize>], distance: usize) -> Path {
+        let mut positions = vec![self.position(index)];
+        while let Some(prev) = previous[index] {
+            positions.push(self.position(prev));
+            index = prev;
+        }
+        positions.reverse();
+
+        Path {
+            distance,
+            positions,
+        }
+    }
+
+    /// Private method running a breadth-first search from `starts`, stopping at the first cell matching `goal`.
+    /// Returns the distances and predecessors of every visited cell, and the index of the goal if reached.
+    #[allow(clippy::type_complexity)]
+    fn inner_bfs(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        mut goal: impl FnMut(Position, &T) -> bool,
+        mut passable: impl FnMut(Position, &T, Position, &T) -> bool,
+    ) -> LibResult<(Vec<Option<usize>>, Vec<Option<usize>>, Option<usize>)> {
+        let mut distances = vec![None; self.grid.len()];
+        let mut previous = vec![None; self.grid.len()];
+        let mut queue = VecDeque::new();
+
+        for start in self.start_indexes(starts)? {
+            if distances[start].is_none() {
+                distances[start] = Some(0);
+                queue.push_back(start);
+            }
+        }
+
+        while let Some(index) = queue.pop_front() {
+            let (pos, value) = (self.position(index), &self.grid[index]);
+            if goal(pos, value) {
+                return Ok((distances, previous, Some(index)));
+            }
+            let distance = distances[index].unwrap_or_default();
+            for (next_pos, next_value) in self.neighbors4(&pos)? {
+                let next = next_pos.y() * self.nb_col + next_pos.x();
+                if distances[next].is_none() && passable(pos, value, next_pos, next_value) {
+                    distances[next] = Some(distance + 1);
+                    previous[next] = Some(index);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        Ok((distances, previous, None))
+    }
+
+    /// Private method running an A* search from `starts`, stopping at the first cell matching `goal`.
+    /// With a null `heuristic`, this is a Dijkstra search.
+    /// Returns the distances and predecessors of every visited cell, and the index of the goal if reached.
+    #[allow(clippy::type_complexity)]
+    fn inner_astar(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        mut goal: impl FnMut(Position, &T) -> bool,
+        mut cost: impl FnMut(Position, &T, Position, &T) -> Option<usize>,
+        mut heuristic: impl FnMut(Position) -> usize,
+    ) -> LibResult<(Vec<Option<usize>>, Vec<Option<usize>>, Option<usize>)> {
+        let mut distances = vec![None; self.grid.len()];
+        let mut previous = vec![None; self.grid.len()];
+        let mut heap = BinaryHeap::new();
+
+        for start in self.start_indexes(starts)? {
+            distances[start] = Some(0);
+            heap.push(Reverse((heuristic(self.position(start)), 0, start)));
+        }
+
+        while let Some(Reverse((_, distance, index))) = heap.pop() {
+            if distances[index].is_some_and(|d| d < distance) {
+                continue;
+            }
+            let (pos, value) = (self.position(index), &self.grid[index]);
+            if goal(pos, value) {
+                return Ok((distances, previous, Some(index)));
+            }
+            for (next_pos, next_value) in self.neighbors4(&pos)? {
+                let Some(move_cost) = cost(pos, value, next_pos, next_value) else {
+                    continue;
+                };
+                let next = next_pos.y() * self.nb_col + next_pos.x();
+                let next_distance = distance + move_cost;
+                if distances[next].is_none_or(|d| next_distance < d) {
+                    distances[next] = Some(next_distance);
+                    previous[next] = Some(index);
+                    heap.push(Reverse((
+                        next_distance + heuristic(next_pos),
+                        next_distance,
+                        next,
+                    )));
+                }
+            }
+        }
+
+        Ok((distances, previous, None))
+    }
+
+    /// Finds the shortest path, in number of moves, from any of the `starts` to the first cell matching `goal`.
+    /// `passable` receives the current position and value, then the neighbour's ones, and tells if the move is allowed.
+    /// `None` is returned if no path exists. Error is raised if one of the starts is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid: Grid<char> = "S.#\n#.#\n..E".parse().unwrap();
+    ///    let start = grid.position_of(&'S').unwrap();
+    ///    let path = grid
+    ///        .bfs([start], |_, c| *c == 'E', |_, _, _, next| *next != '#')
+    ///        .unwrap()
+    ///        .unwrap();
+    ///    assert_eq!(path.distance(), 4);
+    ///    assert_eq!(path.positions().last(), Some(&Position::new(2, 2)));
+    /// ```
+    pub fn bfs(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        goal: impl FnMut(Position, &T) -> bool,
+        passable: impl FnMut(Position, &T, Position, &T) -> bool,
+    ) -> LibResult<Option<Path>> {
+        let (distances, previous, reached) = self.inner_bfs(starts, goal, passable)?;
+
+        Ok(reached
+            .map(|index| self.rebuild_path(index, &previous, distances[index].unwrap_or_default())))
+    }
+
+    /// Computes the number of moves needed to reach every cell from the closest of the `starts`.
+    /// `passable` works like in [Grid::bfs]. Unreachable cells are `None`.
+    /// Error is raised if one of the starts is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid: Grid<char> = "..#\n#.#".parse().unwrap();
+    ///    let distances = grid.bfs_distances([Position::new(0, 0)], |_, _, _, next| *next != '#').unwrap();
+    ///    assert_eq!(distances.iter().collect::<Vec<_>>(), vec![&Some(0), &Some(1), &None, &None, &Some(2), &None]);
+    /// ```
+    pub fn bfs_distances(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        passable: impl FnMut(Position, &T, Position, &T) -> bool,
+    ) -> LibResult<Grid<Option<usize>>> {
+        let (distances, _, _) = self.inner_bfs(starts, |_, _| false, passable)?;
+
+        Grid::try_from((distances, self.nb_col))
+    }
+
+    /// Finds the cheapest path from any of the `starts` to the first cell matching `goal`.
+    /// `cost` receives the current position and value, then the neighbour's ones, and returns the cost of the move,
+    /// or `None` if it isn't allowed.
+    /// `None` is returned if no path exists. Error is raised if one of the starts is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 9, 1, 1, 1, 1], 3)).unwrap();
+    ///    let goal = Position::new(2, 0);
+    ///    let path = grid
+    ///        .dijkstra([Position::new(0, 0)], |pos, _| pos == goal, |_, _, _, next| Some(*next))
+    ///        .unwrap()
+    ///        .unwrap();
+    ///    assert_eq!(path.distance(), 4);
+    ///    assert_eq!(path.positions().len(), 5);
+    /// ```
+    pub fn dijkstra(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        goal: impl FnMut(Position, &T) -> bool,
+        cost: impl FnMut(Position, &T, Position, &T) -> Option<usize>,
+    ) -> LibResult<Option<Path>> {
+        self.astar(starts, goal, cost, |_| 0)
+    }
+
+    /// Computes the cost of the cheapest path to every cell from any of the `starts`.
+    /// `cost` works like in [Grid::dijkstra]. Unreachable cells are `None`.
+    /// Error is raised if one of the starts is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 9, 1, 1, 1, 1], 3)).unwrap();
+    ///    let distances = grid.distances([Position::new(0, 0)], |_, _, _, next| Some(*next)).unwrap();
+    ///    assert_eq!(distances.get(&Position::new(2, 0)).unwrap(), &Some(4));
+    /// ```
+    pub fn distances(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        cost: impl FnMut(Position, &T, Position, &T) -> Option<usize>,
+    ) -> LibResult<Grid<Option<usize>>> {
+        let (distances, _, _) = self.inner_astar(starts, |_, _| false, cost, |_| 0)?;
+
+        Grid::try_from((distances, self.nb_col))
+    }
+
+    /// Finds the cheapest path from any of the `starts` to the first cell matching `goal`, guided by `heuristic`.
+    /// `cost` works like in [Grid::dijkstra]. `heuristic` estimates the remaining cost from a position to the goal,
+    /// and must never overestimate it for the path to be the cheapest one.
+    /// `None` is returned if no path exists. Error is raised if one of the starts is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 9, 1, 1, 1, 1], 3)).unwrap();
+    ///    let goal = Position::new(2, 0);
+    ///    let manhattan = |pos: Position| pos.x().abs_diff(goal.x()) + pos.y().abs_diff(goal.y());
+    ///    let path = grid
+    ///        .astar([Position::new(0, 0)], |pos, _| pos == goal, |_, _, _, next| Some(*next), manhattan)
+    ///        .unwrap()
+    ///        .unwrap();
+    ///    assert_eq!(path.distance(), 4);
+    /// ```
+    pub fn astar(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        goal: impl FnMut(Position, &T) -> bool,
+        cost: impl FnMut(Position, &T, Position, &T) -> Option<usize>,
+        heuristic: impl FnMut(Position) -> usize,
+    ) -> LibResult<Option<Path>> {
+        let (distances, previous, reached) = self.inner_astar(starts, goal, cost, heuristic)?;
+
+        Ok(reached
+            .map(|index| self.rebuild_path(index, &previous, distances[index].unwrap_or_default())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::{Error, Grid},
+        position::Position,
+    };
+
+    const HILL: &str = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+
+    const RISK: &str = "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n1319128137\n1359912421\n3125421639\n1293138521\n2311944581";
+
+    fn elevation(c: char) -> u8 {
+        match c {
+            'S' => b'a',
+            'E' => b'z',
+            c => c as u8,
+        }
+    }
+
+    fn climbable(_: Position, from: &char, _: Position, to: &char) -> bool {
+        elevation(*to) <= elevation(*from) + 1
+    }
+
+    fn risk_grid() -> anyhow::Result<Grid<usize>> {
+        Ok(Grid::parse_with(RISK, |_, c| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| anyhow::anyhow!("{c}"))
+        })?)
+    }
+
+    #[test]
+    fn bfs() -> anyhow::Result<()> {
+        let g: Grid<char> = HILL.parse()?;
+        let start = g.position_of(&'S').unwrap();
+
+        let path = g.bfs([start], |_, c| *c == 'E', climbable)?.unwrap();
+        assert_eq!(path.distance(), 31);
+        assert_eq!(path.positions().len(), 32);
+        assert_eq!(path.positions()[0], start);
+        assert_eq!(path.positions()[31], g.position_of(&'E').unwrap());
+        // Every step is a move to an orthogonal neighbour.
+        assert!(path
+            .positions()
+            .windows(2)
+            .all(|w| w[0].x().abs_diff(w[1].x()) + w[0].y().abs_diff(w[1].y()) == 1));
+
+        // Multi-source.
+        let starts = g
+            .enumerate()
+            .filter(|(_, c)| elevation(**c) == b'a')
+            .map(|(pos, _)| pos);
+        let path = g.bfs(starts, |_, c| *c == 'E', climbable)?.unwrap();
+        assert_eq!(path.distance(), 29);
+
+        // Unreachable goal.
+        assert!(g.bfs([start], |_, c| *c == '#', climbable)?.is_none());
+
+        // Invalid start.
+        assert!(matches!(
+            g.bfs([Position::new(8, 0)], |_, c| *c == 'E', climbable),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn bfs_distances() -> anyhow::Result<()> {
+        let g: Grid<char> = HILL.parse()?;
+        let end = g.position_of(&'E').unwrap();
+
+        // Walking backward from the end.
+        let distances = g.bfs_distances([end], |from_pos, from, to_pos, to| {
+            climbable(to_pos, to, from_pos, from)
+        })?;
+        assert_eq!(distances.get(&g.position_of(&'S').unwrap())?, &Some(31));
+        assert_eq!(distances.get(&end)?, &Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn dijkstra() -> anyhow::Result<()> {
+        let g = risk_grid()?;
+        let goal = Position::new(9, 9);
+
+        let path = g
+            .dijkstra(
+                [Position::new(0, 0)],
+                |pos, _| pos == goal,
+                |_, _, _, to| Some(*to),
+            )?
+            .unwrap();
+        assert_eq!(path.distance(), 40);
+        assert_eq!(
+            path.positions()
+                .iter()
+                .skip(1)
+                .map(|pos| g.get(pos).unwrap())
+                .sum::<usize>(),
+            40
+        );
+
+        let distances = g.distances([Position::new(0, 0)], |_, _, _, to| Some(*to))?;
+        assert_eq!(distances.get(&goal)?, &Some(40));
+
+        // Impassable column walling the goal off.
+        assert!(g
+            .dijkstra(
+                [Position::new(0, 0)],
+                |pos, _| pos == goal,
+                |_, _, to_pos, to| (to_pos.x() != 5).then_some(*to)
+            )?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn astar() -> anyhow::Result<()> {
+        let g = risk_grid()?;
+        let goal = Position::new(9, 9);
+        let manhattan = |pos: Position| pos.x().abs_diff(goal.x()) + pos.y().abs_diff(goal.y());
+
+        let path = g
+            .astar(
+                [Position::new(0, 0)],
+                |pos, _| pos == goal,
+                |_, _, _, to| Some(*to),
+                manhattan,
+            )?
+            .unwrap();
+        assert_eq!(path.distance(), 40);
+        assert_eq!(path.positions().first(), Some(&Position::new(0, 0)));
+        assert_eq!(path.positions().last(), Some(&goal));
+
+        Ok(())
+    }
+}
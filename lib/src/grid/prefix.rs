@@ -0,0 +1,274 @@
+//! Prefix module. 2D prefix sums, for constant time rectangle updates and rectangle sums over numeric grids.
+
+use std::ops::{Add, Sub};
+
+use crate::{
+    error::LibResult,
+    grid::{check_rect, Grid},
+    position::Position,
+};
+
+/// Private function computing the 2D prefix sums of `cells`, in place, every cell becoming the sum of the rectangle
+/// going from the top left corner to itself. Values must not be negative for unsigned types to never underflow.
+fn prefix_sums<T>(cells: &mut [T], nb_col: usize)
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    for index in 0..cells.len() {
+        let (x, y) = (index % nb_col, index / nb_col);
+        if x > 0 {
+            cells[index] = cells[index] + cells[index - 1];
+        }
+        if y > 0 {
+            cells[index] = cells[index] + cells[index - nb_col];
+        }
+        if x > 0 && y > 0 {
+            cells[index] = cells[index] - cells[index - nb_col - 1];
+        }
+    }
+}
+
+/// A 2D difference array: adds a value to a whole rectangle in constant time, then materialises every cell into a [Grid].
+/// Added and removed parts are kept apart, so that unsigned types can be used as long as values aren't negative.
+#[derive(Debug, Clone)]
+pub struct DiffGrid<T> {
+    base: Vec<T>,
+    added: Vec<T>,
+    removed: Vec<T>,
+    nb_row: usize,
+    nb_col: usize,
+}
+
+/// Starts from the values of a [Grid] instead of zeroes.
+impl<T> From<&Grid<T>> for DiffGrid<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    fn from(grid: &Grid<T>) -> Self {
+        let mut diff = Self::new(grid.nb_row, grid.nb_col);
+        diff.base.copy_from_slice(&grid.grid);
+        diff
+    }
+}
+
+impl<T> DiffGrid<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Creates a new DiffGrid of `nb_row` rows and `nb_col` columns, every cell being `T::default()`.
+    pub fn new(nb_row: usize, nb_col: usize) -> Self {
+        Self {
+            base: vec![T::default(); nb_row * nb_col],
+            added: vec![T::default(); nb_row * nb_col],
+            removed: vec![T::default(); nb_row * nb_col],
+            nb_row,
+            nb_col,
+        }
+    }
+
+    /// Retrieves the number of rows of the [DiffGrid].
+    pub fn nb_row(&self) -> usize {
+        self.nb_row
+    }
+
+    /// Retrieves the number of columns of the [DiffGrid].
+    pub fn nb_col(&self) -> usize {
+        self.nb_col
+    }
+
+    /// Adds `value` to every cell of the rectangle defined by `top_left` and `bottom_right`, both included.
+    /// Error is raised if the rectangle isn't valid, like [Grid::iter_rect].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::DiffGrid, position::Position};
+    ///
+    ///    let mut diff = DiffGrid::new(2, 3);
+    ///    diff.add_rect(Position::new(0, 0), Position::new(1, 1), 2u32).unwrap();
+    ///    diff.add_rect(Position::new(1, 1), Position::new(2, 1), 5).unwrap();
+    ///    assert_eq!(diff.to_grid().iter().collect::<Vec<_>>(), vec![&2, &2, &0, &2, &7, &5]);
+    ///    assert!(diff.add_rect(Position::new(2, 0), Position::new(1, 1), 1).is_err());
+    /// ```
+    pub fn add_rect(
+        &mut self,
+        top_left: Position,
+        bottom_right: Position,
+        value: T,
+    ) -> LibResult<()> {
+        check_rect(self.nb_row, self.nb_col, &top_left, &bottom_right)?;
+
+        let (x1, y1) = (top_left.x(), top_left.y());
+        let (x2, y2) = (bottom_right.x() + 1, bottom_right.y() + 1);
+        let (in_x, in_y) = (x2 < self.nb_col, y2 < self.nb_row);
+        let index = |x: usize, y: usize| y * self.nb_col + x;
+
+        let corner = index(x1, y1);
+        self.added[corner] = self.added[corner] + value;
+        if in_x {
+            let corner = index(x2, y1);
+            self.removed[corner] = self.removed[corner] + value;
+        }
+        if in_y {
+            let corner = index(x1, y2);
+            self.removed[corner] = self.removed[corner] + value;
+        }
+        if in_x && in_y {
+            let corner = index(x2, y2);
+            self.added[corner] = self.added[corner] + value;
+        }
+
+        Ok(())
+    }
+
+    /// Materialises every cell into a [Grid].
+    pub fn to_grid(&self) -> Grid<T> {
+        let mut added = self.added.clone();
+        let mut removed = self.removed.clone();
+        prefix_sums(&mut added, self.nb_col);
+        prefix_sums(&mut removed, self.nb_col);
+
+        Grid {
+            grid: self
+                .base
+                .iter()
+                .zip(added)
+                .zip(removed)
+                .map(|((base, added), removed)| *base + added - removed)
+                .collect(),
+            nb_row: self.nb_row,
+            nb_col: self.nb_col,
+        }
+    }
+}
+
+/// A summed-area table, retrieving the sum of any rectangle of a [Grid] in constant time.
+#[derive(Debug, Clone)]
+pub struct SummedArea<T> {
+    sums: Vec<T>,
+    nb_row: usize,
+    nb_col: usize,
+}
+
+impl<T> From<&Grid<T>> for SummedArea<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    fn from(grid: &Grid<T>) -> Self {
+        let mut sums = grid.grid.clone();
+        prefix_sums(&mut sums, grid.nb_col);
+
+        Self {
+            sums,
+            nb_row: grid.nb_row,
+            nb_col: grid.nb_col,
+        }
+    }
+}
+
+impl<T> SummedArea<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Retrieves the sum of the rectangle defined by `top_left` and `bottom_right`, both included.
+    /// Error is raised if the rectangle isn't valid, like [Grid::iter_rect].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::{Grid, SummedArea}, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![
+    ///            1u64, 2, 3, 4, 5, 6, 7, 8, 9
+    ///        ], 3)).unwrap();
+    ///    let sums = SummedArea::from(&grid);
+    ///    assert_eq!(sums.sum_rect(Position::new(1, 1), Position::new(2, 2)).unwrap(), 28);
+    ///    assert_eq!(sums.sum_rect(Position::new(0, 0), Position::new(2, 2)).unwrap(), 45);
+    ///    assert!(sums.sum_rect(Position::new(0, 0), Position::new(3, 2)).is_err());
+    /// ```
+    pub fn sum_rect(&self, top_left: Position, bottom_right: Position) -> LibResult<T> {
+        check_rect(self.nb_row, self.nb_col, &top_left, &bottom_right)?;
+
+        let sum = |x: Option<usize>, y: Option<usize>| match (x, y) {
+            (Some(x), Some(y)) => self.sums[y * self.nb_col + x],
+            _ => T::default(),
+        };
+        let (x1, y1) = (top_left.x().checked_sub(1), top_left.y().checked_sub(1));
+        let (x2, y2) = (Some(bottom_right.x()), Some(bottom_right.y()));
+
+        // Adding first so that unsigned types never underflow.
+        Ok(sum(x2, y2) + sum(x1, y1) - sum(x1, y2) - sum(x2, y1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::{DiffGrid, Error, Grid, SummedArea},
+        position::Position,
+    };
+
+    #[test]
+    fn diff_grid() -> anyhow::Result<()> {
+        let rects = [
+            (Position::new(0, 0), Position::new(4, 3), 1),
+            (Position::new(2, 1), Position::new(2, 1), 7),
+            (Position::new(1, 2), Position::new(4, 3), 3),
+            (Position::new(0, 3), Position::new(4, 3), 2),
+        ];
+
+        // Compared with a naive update of every cell, on top of initial values.
+        let mut grid = Grid::try_from(((0..20).collect::<Vec<usize>>(), 5))?;
+        let mut diff = DiffGrid::from(&grid);
+        for (top_left, bottom_right, value) in rects {
+            grid.iter_rect_mut(top_left, bottom_right)?
+                .for_each(|v| *v += value);
+            diff.add_rect(top_left, bottom_right, value)?;
+        }
+        assert_eq!(
+            diff.to_grid().iter().collect::<Vec<_>>(),
+            grid.iter().collect::<Vec<_>>()
+        );
+
+        // Signed values.
+        let mut diff = DiffGrid::new(2, 2);
+        diff.add_rect(Position::new(0, 0), Position::new(1, 1), -3i64)?;
+        diff.add_rect(Position::new(1, 0), Position::new(1, 1), 5)?;
+        assert_eq!(
+            diff.to_grid().iter().collect::<Vec<_>>(),
+            vec![&-3, &2, &-3, &2]
+        );
+
+        assert!(matches!(
+            diff.add_rect(Position::new(1, 1), Position::new(0, 1), 1),
+            Err(LibError::Grid(Error::InvalidRectangle(_, _)))
+        ));
+        assert!(matches!(
+            diff.add_rect(Position::new(0, 0), Position::new(0, 2), 1),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn summed_area() -> anyhow::Result<()> {
+        let grid = Grid::try_from(((1..=20).collect::<Vec<u32>>(), 4))?;
+        let sums = SummedArea::from(&grid);
+
+        // Compared with a naive sum over every rectangle.
+        for (y1, x1) in (0..5).flat_map(|y| (0..4).map(move |x| (y, x))) {
+            for (y2, x2) in (y1..5).flat_map(|y| (x1..4).map(move |x| (y, x))) {
+                let (top_left, bottom_right) = (Position::new(x1, y1), Position::new(x2, y2));
+                assert_eq!(
+                    sums.sum_rect(top_left, bottom_right)?,
+                    grid.iter_rect(top_left, bottom_right)?.sum::<u32>()
+                );
+            }
+        }
+
+        assert!(matches!(
+            sums.sum_rect(Position::new(3, 0), Position::new(0, 4)),
+            Err(LibError::Grid(Error::InvalidRectangle(_, _)))
+        ));
+
+        Ok(())
+    }
+}
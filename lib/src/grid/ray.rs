@@ -0,0 +1,106 @@
+//! Ray module. Line-of-sight iterators walking from a cell to the edge of a [Grid].
+
+use std::iter::successors;
+
+use crate::{
+    error::{LibError, LibResult},
+    grid::{Connectivity, Error, Grid},
+    position::Position,
+};
+
+impl<T> Grid<T> {
+    /// Yields an [Iterator] of positions and references over the cells met when walking from `pos` by `step`,
+    /// until the edge of the [Grid]. `pos` itself isn't yielded. A step is a `(dx, dy)` tuple, like the offsets
+    /// of [Grid::neighbors_with_positions], so diagonals can be walked too. A `(0, 0)` step yields nothing.
+    /// Error is raised if `pos` is outside of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let right = grid.ray(&Position::new(0, 1), (1, 0)).unwrap().map(|(_, v)| *v).collect::<Vec<_>>();
+    ///    assert_eq!(right, vec![5, 6]);
+    ///    let diagonal = grid.ray(&Position::new(2, 2), (-1, -1)).unwrap().collect::<Vec<_>>();
+    ///    assert_eq!(diagonal, vec![(Position::new(1, 1), &5), (Position::new(0, 0), &1)]);
+    /// ```
+    pub fn ray(
+        &self,
+        pos: &Position,
+        step: (isize, isize),
+    ) -> LibResult<impl Iterator<Item = (Position, &T)> + Clone> {
+        if pos.x() >= self.nb_col || pos.y() >= self.nb_row {
+            return Err(LibError::from(Error::InvalidPosition(*pos)));
+        }
+
+        let (dx, dy) = step;
+        let first = (step != (0, 0)).then_some(*pos);
+        Ok(successors(first, move |pos| {
+            let x = pos.x().checked_add_signed(dx)?;
+            let y = pos.y().checked_add_signed(dy)?;
+            (x < self.nb_col && y < self.nb_row).then(|| Position::new(x, y))
+        })
+        .skip(1)
+        .map(|pos| (pos, &self.grid[pos.y() * self.nb_col + pos.x()])))
+    }
+
+    /// Yields an [Iterator] of rays from `pos`, one per connected neighbour, in row-major order. See [Grid::ray].
+    /// Error is raised if `pos` is outside of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::{Connectivity, Grid}, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let lengths = grid.rays(&Position::new(1, 0), Connectivity::Eight).unwrap().map(|ray| ray.count()).collect::<Vec<_>>();
+    ///    assert_eq!(lengths, vec![0, 0, 0, 1, 1, 1, 2, 1]);
+    ///    let sums = grid.rays(&Position::new(1, 1), Connectivity::Four).unwrap().map(|ray| ray.map(|(_, v)| v).sum::<i32>()).collect::<Vec<_>>();
+    ///    assert_eq!(sums, vec![2, 4, 6, 8]);
+    /// ```
+    pub fn rays(
+        &self,
+        pos: &Position,
+        connectivity: Connectivity,
+    ) -> LibResult<impl Iterator<Item = impl Iterator<Item = (Position, &T)> + Clone>> {
+        let rays = connectivity
+            .offsets()
+            .iter()
+            .map(|step| self.ray(pos, *step))
+            .collect::<LibResult<Vec<_>>>()?;
+
+        Ok(rays.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::{Connectivity, Error, Grid},
+        position::Position,
+    };
+
+    #[test]
+    fn ray() -> anyhow::Result<()> {
+        // Not square, so that rows and columns can't be mixed up.
+        let grid = Grid::try_from(((0..12).collect::<Vec<_>>(), 4))?;
+
+        let values = |pos: Position, step: (isize, isize)| -> anyhow::Result<Vec<usize>> {
+            Ok(grid.ray(&pos, step)?.map(|(_, v)| *v).collect())
+        };
+        assert_eq!(values(Position::new(0, 0), (1, 0))?, vec![1, 2, 3]);
+        assert_eq!(values(Position::new(0, 0), (0, 1))?, vec![4, 8]);
+        assert_eq!(values(Position::new(3, 2), (-1, -1))?, vec![6, 1]);
+        assert_eq!(values(Position::new(0, 2), (2, -1))?, vec![6]);
+        assert_eq!(values(Position::new(3, 0), (1, 0))?, vec![]);
+        assert_eq!(values(Position::new(1, 1), (0, 0))?, vec![]);
+
+        assert!(matches!(
+            grid.ray(&Position::new(4, 0), (1, 0)),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+
+        let rays = grid.rays(&Position::new(1, 1), Connectivity::Eight)?;
+        assert_eq!(rays.map(|ray| ray.count()).sum::<usize>(), 9);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+//! Region module. Flood fill and connected-component labelling over a [Grid].
+
+use std::collections::VecDeque;
+
+use crate::{
+    error::LibResult,
+    grid::{Grid, NEIGHBORS4, NEIGHBORS8},
+    position::Position,
+};
+
+/// Which neighbours of a cell are connected to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Only the 4 orthogonal neighbours are connected.
+    Four,
+    /// The 8 surrounding neighbours, diagonals included, are connected.
+    Eight,
+}
+
+impl Connectivity {
+    /// Private method retrieving the offsets of the connected neighbours.
+    pub(crate) fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &NEIGHBORS4,
+            Connectivity::Eight => &NEIGHBORS8,
+        }
+    }
+}
+
+/// Statistics of a connected component, see [Grid::components].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    start: Position,
+    area: usize,
+    top_left: Position,
+    bottom_right: Position,
+    perimeter: usize,
+}
+
+impl Component {
+    /// Retrieves the first cell of the component, in row-major order.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Retrieves the number of cells of the component.
+    pub fn area(&self) -> usize {
+        self.area
+    }
+
+    /// Retrieves the top left corner of the component's bounding box.
+    pub fn top_left(&self) -> Position {
+        self.top_left
+    }
+
+    /// Retrieves the bottom right corner of the component's bounding box.
+    pub fn bottom_right(&self) -> Position {
+        self.bottom_right
+    }
+
+    /// Retrieves the number of cell edges separating the component from other cells or from the outside of the [Grid].
+    /// Only orthogonal edges are counted, even with [Connectivity::Eight].
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+}
+
+impl<T> Grid<T> {
+    /// Retrieves every cell connected to `start` through cells matching `predicate`, in breadth-first order.
+    /// `start` is included if it matches `predicate`, otherwise nothing is returned.
+    /// Error is raised if `start` is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::{Connectivity, Grid}, position::Position};
+    ///
+    ///    let grid: Grid<char> = "##.\n..#\n.##".parse().unwrap();
+    ///    let start = Position::new(0, 0);
+    ///    assert_eq!(grid.flood_fill(start, Connectivity::Four, |_, c| *c == '#').unwrap().len(), 2);
+    ///    assert_eq!(grid.flood_fill(start, Connectivity::Eight, |_, c| *c == '#').unwrap().len(), 5);
+    /// ```
+    pub fn flood_fill(
+        &self,
+        start: Position,
+        connectivity: Connectivity,
+        mut predicate: impl FnMut(Position, &T) -> bool,
+    ) -> LibResult<Vec<Position>> {
+        let value = self.get(&start)?;
+        if !predicate(start, value) {
+            return Ok(Vec::new());
+        }
+
+        let mut visited = vec![false; self.grid.len()];
+        let mut queue = VecDeque::from([start]);
+        let mut filled = Vec::new();
+        visited[start.y() * self.nb_col + start.x()] = true;
+
+        while let Some(pos) = queue.pop_front() {
+            for (index, next) in self.neighbor_indexes(&pos, connectivity.offsets())? {
+                if !visited[index] && predicate(next, &self.grid[index]) {
+                    visited[index] = true;
+                    queue.push_back(next);
+                }
+            }
+            filled.push(pos);
+        }
+
+        Ok(filled)
+    }
+
+    /// Labels every cell with the index of its connected component, components being numbered in row-major order
+    /// of their first cell. Two neighbouring cells belong to the same component if `connected` holds for their values.
+    /// Returns the labels along with the statistics of every component, indexed by label.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::{Connectivity, Grid}, position::Position};
+    ///
+    ///    let grid: Grid<char> = "AAB\nABB\nCCB".parse().unwrap();
+    ///    let (labels, components) = grid.components(Connectivity::Four, |a, b| a == b);
+    ///    assert_eq!(labels.iter().collect::<Vec<_>>(), vec![&0, &0, &1, &0, &1, &1, &2, &2, &1]);
+    ///    assert_eq!(components[1].area(), 4);
+    ///    assert_eq!(components[1].perimeter(), 10);
+    ///    assert_eq!(components[1].top_left(), Position::new(1, 0));
+    ///    assert_eq!(components[1].bottom_right(), Position::new(2, 2));
+    /// ```
+    pub fn components(
+        &self,
+        connectivity: Connectivity,
+        mut connected: impl FnMut(&T, &T) -> bool,
+    ) -> (Grid<usize>, Vec<Component>) {
+        let mut labels = vec![usize::MAX; self.grid.len()];
+        let mut components = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for first in 0..self.grid.len() {
+            if labels[first] != usize::MAX {
+                continue;
+            }
+
+            let label = components.len();
+            let start = self.position(first);
+            let mut component = Component {
+                start,
+                area: 0,
+                top_left: start,
+                bottom_right: start,
+                perimeter: 0,
+            };
+            labels[first] = label;
+            queue.push_back((first, start));
+
+            while let Some((index, pos)) = queue.pop_front() {
+                component.area += 1;
+                component.top_left = Position::new(
+                    component.top_left.x().min(pos.x()),
+                    component.top_left.y().min(pos.y()),
+                );
+                component.bottom_right = Position::new(
+                    component.bottom_right.x().max(pos.x()),
+                    component.bottom_right.y().max(pos.y()),
+                );
+
+                // Positions come from the Grid itself, so no error can be raised.
+                for (next, next_pos) in self
+                    .neighbor_indexes(&pos, connectivity.offsets())
+                    .unwrap_or_default()
+                {
+                    if labels[next] == usize::MAX && connected(&self.grid[index], &self.grid[next])
+                    {
+                        labels[next] = label;
+                        queue.push_back((next, next_pos));
+                    }
+                }
+            }
+            components.push(component);
+        }
+
+        for (index, &label) in labels.iter().enumerate() {
+            let inner = self
+                .neighbor_indexes(&self.position(index), &NEIGHBORS4)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|&(next, _)| labels[next] == label)
+                .count();
+            components[label].perimeter += 4 - inner;
+        }
+
+        (
+            Grid {
+                grid: labels,
+                nb_row: self.nb_row,
+                nb_col: self.nb_col,
+            },
+            components,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::{Connectivity, Error, Grid},
+        position::Position,
+    };
+
+    const GARDEN: &str = "RRRRIICCFF\nRRRRIICCCF\nVVRRRCCFFF\nVVRCCCJFFF\nVVVVCJJCFE\nVVIVCCJJEE\nVVIIICJJEE\nMIIIIIJJEE\nMIIISIJEEE\nMMMISSJEEE";
+
+    #[test]
+    fn flood_fill() -> anyhow::Result<()> {
+        let g: Grid<char> = GARDEN.parse()?;
+
+        let filled = g.flood_fill(Position::new(0, 0), Connectivity::Four, |_, c| *c == 'R')?;
+        assert_eq!(filled.len(), 12);
+        assert_eq!(filled[0], Position::new(0, 0));
+        assert!(filled.iter().all(|pos| g.get(pos).is_ok_and(|c| *c == 'R')));
+
+        // Start not matching the predicate.
+        assert!(g
+            .flood_fill(Position::new(0, 0), Connectivity::Four, |_, c| *c == 'I')?
+            .is_empty());
+
+        // Only diagonals join the cells of an X.
+        let x: Grid<char> = "#.#\n.#.\n#.#".parse()?;
+        let four = x.flood_fill(Position::new(1, 1), Connectivity::Four, |_, c| *c == '#')?;
+        let eight = x.flood_fill(Position::new(1, 1), Connectivity::Eight, |_, c| *c == '#')?;
+        assert_eq!((four.len(), eight.len()), (1, 5));
+
+        assert!(matches!(
+            g.flood_fill(Position::new(10, 0), Connectivity::Four, |_, _| true),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn components() -> anyhow::Result<()> {
+        let g: Grid<char> = GARDEN.parse()?;
+
+        let (labels, components) = g.components(Connectivity::Four, |a, b| a == b);
+        assert_eq!(components.len(), 11);
+        assert_eq!(
+            components
+                .iter()
+                .map(|c| c.area() * c.perimeter())
+                .sum::<usize>(),
+            1930
+        );
+        assert_eq!(components.iter().map(|c| c.area()).sum::<usize>(), 100);
+        for (label, component) in components.iter().enumerate() {
+            assert_eq!(labels.get(&component.start())?, &label);
+        }
+
+        let r = &components[0];
+        assert_eq!((r.area(), r.perimeter()), (12, 18));
+        assert_eq!(
+            (r.top_left(), r.bottom_right()),
+            (Position::new(0, 0), Position::new(4, 3))
+        );
+
+        // Islands of '#', every other cell being water.
+        let g: Grid<char> = "#..#\n.#..\n...#".parse()?;
+        let (_, four) = g.components(Connectivity::Four, |a, b| a == b);
+        let (labels, eight) = g.components(Connectivity::Eight, |a, b| a == b);
+        assert_eq!((four.len(), eight.len()), (5, 4));
+        assert_eq!(labels.get(&Position::new(1, 1))?, &0);
+        assert_eq!(
+            eight
+                .iter()
+                .filter(|c| g.get(&c.start()).is_ok_and(|v| *v == '#'))
+                .count(),
+            3
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+//! Render module. Configurable text rendering of any [Grid].
+
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    io::{stdout, IsTerminal},
+};
+
+use colored::{Color, Colorize};
+
+use crate::{
+    grid::{Grid, GridView},
+    position::Position,
+};
+
+/// Closure choosing the colour of a cell, if any.
+type CellColor<'a, T> = Box<dyn Fn(&T) -> Option<Color> + 'a>;
+
+/// Renders a [Grid] or a [GridView] through a cell-to-string closure. Created with [Grid::renderer] or [Grid::display].
+/// Every cell is right-aligned on the width of the widest rendered cell.
+/// Colours are only used when stdout is a TTY, unless forced with [GridRenderer::color].
+pub struct GridRenderer<'a, T> {
+    grid: GridView<'a, T>,
+    cell: Box<dyn Fn(&T) -> String + 'a>,
+    cell_color: Option<CellColor<'a, T>>,
+    separator: String,
+    border: bool,
+    rulers: bool,
+    highlights: HashSet<Position>,
+    highlight_char: Option<char>,
+    color: bool,
+}
+
+impl<'a, T> GridRenderer<'a, T> {
+    /// Creates a new renderer for `grid`, every cell being rendered by `cell`.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::{Grid, GridRenderer};
+    ///
+    ///    let grid = Grid::try_from((vec![1u8, 0, 0, 1], 2)).unwrap();
+    ///    let renderer = GridRenderer::new(&grid, |v| if *v == 1 { "#".into() } else { ".".into() });
+    ///    assert_eq!(renderer.to_string(), "#.\n.#\n");
+    /// ```
+    pub fn new(grid: &'a Grid<T>, cell: impl Fn(&T) -> String + 'a) -> Self {
+        Self::from_view(grid.as_view(), cell)
+    }
+
+    /// Creates a new renderer for a view, every cell being rendered by `cell`.
+    pub(crate) fn from_view(grid: GridView<'a, T>, cell: impl Fn(&T) -> String + 'a) -> Self {
+        Self {
+            grid,
+            cell: Box::new(cell),
+            cell_color: None,
+            separator: String::new(),
+            border: false,
+            rulers: false,
+            highlights: HashSet::new(),
+            highlight_char: None,
+            color: stdout().is_terminal(),
+        }
+    }
+
+    /// Colours every cell with the colour returned by `cell_color`, if any.
+    pub fn cell_color(mut self, cell_color: impl Fn(&T) -> Option<Color> + 'a) -> Self {
+        self.cell_color = Some(Box::new(cell_color));
+        self
+    }
+
+    /// Sets the string written between two cells of the same row. Default is empty.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 20, 300, 4], 2)).unwrap();
+    ///    assert_eq!(grid.display().separator(" ").to_string(), "  1  20\n300   4\n");
+    /// ```
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Draws a `-`/`|` border around the grid. Default is `false`.
+    pub fn border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Draws the column indexes (modulo 10) above the grid and the row indexes on its left. Default is `false`.
+    pub fn rulers(mut self, rulers: bool) -> Self {
+        self.rulers = rulers;
+        self
+    }
+
+    /// Highlights every cell at `positions`, eg. to overlay a path.
+    /// Highlighted cells are drawn reversed when colours are used, or replaced by the highlight char
+    /// if one is set with [GridRenderer::highlight_char].
+    pub fn highlight(mut self, positions: impl IntoIterator<Item = Position>) -> Self {
+        self.highlights.extend(positions);
+        self
+    }
+
+    /// Draws highlighted cells with `c` instead of their own rendering.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid: Grid<char> = "...\n...".parse().unwrap();
+    ///    let rendered = grid
+    ///        .display()
+    ///        .highlight([Position::new(0, 0), Position::new(1, 1)])
+    ///        .highlight_char('o')
+    ///        .to_string();
+    ///    assert_eq!(rendered, "o..\n.o.\n");
+    /// ```
+    pub fn highlight_char(mut self, c: char) -> Self {
+        self.highlight_char = Some(c);
+        self
+    }
+
+    /// Forces colours on or off. By default, colours are only used when stdout is a TTY.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl<'a, T> Display for GridRenderer<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (nb_row, nb_col) = (self.grid.nb_row(), self.grid.nb_col());
+        let cells = self
+            .grid
+            .enumerate()
+            .map(|(pos, v)| match self.highlight_char {
+                Some(c) if self.highlights.contains(&pos) => c.to_string(),
+                _ => (self.cell)(v),
+            })
+            .collect::<Vec<_>>();
+        let width = cells.iter().map(|c| c.chars().count()).max().unwrap_or(1);
+        let row_width = nb_col * width + nb_col.saturating_sub(1) * self.separator.chars().count();
+        let margin = if self.rulers {
+            " ".repeat(nb_row.saturating_sub(1).to_string().len())
+        } else {
+            String::new()
+        };
+
+        if self.rulers {
+            f.write_str(&margin)?;
+            if self.border {
+                f.write_str(" ")?;
+            }
+            let ruler = (0..nb_col)
+                .map(|col| format!("{:>width$}", col % 10))
+                .collect::<Vec<_>>()
+                .join(&self.separator);
+            writeln!(f, "{ruler}")?;
+        }
+        if self.border {
+            writeln!(f, "{margin}{}", "-".repeat(row_width + 2))?;
+        }
+
+        for (row, row_cells) in cells.chunks(nb_col.max(1)).enumerate() {
+            if self.rulers {
+                write!(f, "{row:>0$}", margin.len())?;
+            }
+            if self.border {
+                f.write_str("|")?;
+            }
+            for (col, cell) in row_cells.iter().enumerate() {
+                if col > 0 {
+                    f.write_str(&self.separator)?;
+                }
+                let pos = Position::new(col, row);
+                let cell = format!("{cell:>width$}");
+                if self.color {
+                    let color = self
+                        .cell_color
+                        .as_ref()
+                        .and_then(|cell_color| self.grid.get(&pos).ok().and_then(cell_color));
+                    let mut colored = match color {
+                        Some(color) => cell.color(color),
+                        None => cell.normal(),
+                    };
+                    if self.highlights.contains(&pos) {
+                        colored = colored.reversed();
+                    }
+                    write!(f, "{colored}")?;
+                } else {
+                    f.write_str(&cell)?;
+                }
+            }
+            if self.border {
+                f.write_str("|")?;
+            }
+            writeln!(f)?;
+        }
+
+        if self.border {
+            writeln!(f, "{margin}{}", "-".repeat(row_width + 2))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a [GridRenderer] rendering every cell with `cell`.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![true, false, false, true], 2)).unwrap();
+    ///    let rendered = grid.renderer(|b| if *b { "#".into() } else { ".".into() }).border(true).to_string();
+    ///    assert_eq!(rendered, "----\n|#.|\n|.#|\n----\n");
+    /// ```
+    pub fn renderer<'a>(&'a self, cell: impl Fn(&T) -> String + 'a) -> GridRenderer<'a, T> {
+        GridRenderer::new(self, cell)
+    }
+
+    /// Creates a [GridRenderer] rendering every cell with its [Display] implementation.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid: Grid<char> = "#..\n.#.".parse().unwrap();
+    ///    assert_eq!(grid.display().to_string(), "#..\n.#.\n");
+    /// ```
+    pub fn display(&self) -> GridRenderer<'_, T>
+    where
+        T: Display,
+    {
+        GridRenderer::new(self, T::to_string)
+    }
+}
+
+impl Display for Grid<bool> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.renderer(|b| if *b { "t".into() } else { "f".into() })
+            .cell_color(|b| Some(if *b { Color::Green } else { Color::Red }))
+            .border(true)
+            .fmt(f)
+    }
+}
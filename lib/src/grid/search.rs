@@ -0,0 +1,242 @@
+//! Search module. Word search in every direction, and stamp matching with wildcards, over a [Grid].
+
+use crate::{
+    grid::{Connectivity, Grid, Transform},
+    position::Position,
+};
+
+/// An occurrence of a word in a [Grid], see [Grid::find_word].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WordMatch {
+    start: Position,
+    step: (isize, isize),
+}
+
+impl WordMatch {
+    /// Retrieves the position of the first letter of the word.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Retrieves the `(dx, dy)` step going from a letter to the next one.
+    pub fn step(&self) -> (isize, isize) {
+        self.step
+    }
+}
+
+/// An occurrence of a stamp in a [Grid], see [Grid::find_stamp].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StampMatch {
+    top_left: Position,
+    transform: Transform,
+}
+
+impl StampMatch {
+    /// Retrieves the position, in the searched [Grid], of the top left corner of the transformed stamp.
+    pub fn top_left(&self) -> Position {
+        self.top_left
+    }
+
+    /// Retrieves the transform applied to the stamp, see [Grid::transformed].
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+}
+
+impl<T> Grid<T> {
+    /// Finds every occurrence of `word` in the 8 directions, diagonals and backwards included, in row-major order
+    /// of their first letter. A palindrome is found once per direction it reads in. A one-letter word is found once per cell, with a `(0, 0)`
+    /// step, and an empty word is never found.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid: Grid<char> = "XMAS\nMM..\nA.A.\nS..S".parse().unwrap();
+    ///    let word = "XMAS".chars().collect::<Vec<_>>();
+    ///    let matches = grid.find_word(&word);
+    ///    assert_eq!(matches.len(), 3);
+    ///    assert!(matches.iter().all(|m| m.start() == Position::new(0, 0)));
+    ///    assert_eq!(matches[2].step(), (1, 1));
+    /// ```
+    pub fn find_word(&self, word: &[T]) -> Vec<WordMatch>
+    where
+        T: PartialEq,
+    {
+        let Some((first, rest)) = word.split_first() else {
+            return Vec::new();
+        };
+        let steps: &[(isize, isize)] = if rest.is_empty() {
+            &[(0, 0)]
+        } else {
+            Connectivity::Eight.offsets()
+        };
+
+        self.enumerate()
+            .filter(|(_, cell)| *cell == first)
+            .flat_map(|(start, _)| {
+                steps
+                    .iter()
+                    .filter(move |step| {
+                        let mut ray = self.ray(&start, **step).expect("start is inside the grid");
+                        rest.iter()
+                            .all(|letter| ray.next().is_some_and(|(_, cell)| cell == letter))
+                    })
+                    .map(move |step| WordMatch { start, step: *step })
+            })
+            .collect()
+    }
+
+    /// Finds every place where `stamp`, through one of `transforms`, matches the [Grid]: every cell of the stamp
+    /// must satisfy `matches(stamp_cell, grid_cell)`, which is where wildcards are handled.
+    /// Transforms giving the same stamp as a previous one are skipped, so that a symmetric stamp matches only once.
+    /// Matches are sorted by transform, in the order of `transforms`, then in row-major order.
+    /// Pass `&[Transform::Identity]` to only look for the stamp as is, or [Transform::ALL] for every orientation.
+    /// An empty stamp, without rows or columns, is never found.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::{Grid, Transform}, position::Position};
+    ///
+    ///    let grid: Grid<char> = "#..#\n###.\n..##".parse().unwrap();
+    ///    let stamp: Grid<char> = "#.\n##".parse().unwrap();
+    ///    let wildcard = |s: &char, c: &char| *s == '.' || s == c;
+    ///    let matches = grid.find_stamp(&stamp, &[Transform::Identity], wildcard);
+    ///    assert_eq!(matches.iter().map(|m| m.top_left()).collect::<Vec<_>>(), vec![Position::new(0, 0), Position::new(2, 1)]);
+    ///    assert_eq!(grid.find_stamp(&stamp, &Transform::ALL, wildcard).len(), 3);
+    /// ```
+    pub fn find_stamp<P: PartialEq>(
+        &self,
+        stamp: &Grid<P>,
+        transforms: &[Transform],
+        matches: impl Fn(&P, &T) -> bool,
+    ) -> Vec<StampMatch> {
+        if stamp.grid.is_empty() {
+            return Vec::new();
+        }
+
+        let mut variants = Vec::new();
+        for transform in transforms {
+            let view = stamp.transformed(*transform);
+            let is_new = variants.iter().all(|(_, other): &(_, Grid<&P>)| {
+                (other.nb_row, other.nb_col) != (view.nb_row(), view.nb_col())
+                    || !other.grid.iter().copied().eq(view.iter())
+            });
+            if is_new {
+                let variant = Grid {
+                    grid: view.iter().collect(),
+                    nb_row: view.nb_row(),
+                    nb_col: view.nb_col(),
+                };
+                variants.push((*transform, variant));
+            }
+        }
+
+        variants
+            .iter()
+            .filter(|(_, variant)| variant.nb_row <= self.nb_row && variant.nb_col <= self.nb_col)
+            .flat_map(|(transform, variant)| {
+                Self::rect_positions(
+                    Position::new(0, 0),
+                    Position::new(self.nb_col - variant.nb_col, self.nb_row - variant.nb_row),
+                )
+                .filter(|top_left| {
+                    variant.enumerate().all(|(pos, cell)| {
+                        let (x, y) = (top_left.x() + pos.x(), top_left.y() + pos.y());
+                        matches(cell, &self.grid[y * self.nb_col + x])
+                    })
+                })
+                .map(|top_left| StampMatch {
+                    top_left,
+                    transform: *transform,
+                })
+                .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        grid::{Grid, Transform},
+        position::Position,
+    };
+
+    const WORDS: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn find_word() -> anyhow::Result<()> {
+        let grid: Grid<char> = WORDS.parse()?;
+        let word = "XMAS".chars().collect::<Vec<_>>();
+        let matches = grid.find_word(&word);
+        assert_eq!(matches.len(), 18);
+        assert_eq!(matches[0].start(), Position::new(4, 0));
+        assert_eq!(matches[0].step(), (1, 1));
+
+        // Every match actually spells the word.
+        for m in &matches {
+            let spelled = std::iter::once(*grid.get(&m.start())?)
+                .chain(grid.ray(&m.start(), m.step())?.map(|(_, c)| *c).take(3))
+                .collect::<String>();
+            assert_eq!(spelled, "XMAS");
+        }
+
+        // Palindromes are found in both directions.
+        let grid: Grid<char> = "ABA\n...".parse()?;
+        assert_eq!(grid.find_word(&['A', 'B', 'A']).len(), 2);
+        assert_eq!(grid.find_word(&['A']).len(), 2);
+        assert!(grid.find_word(&[]).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_stamp() -> anyhow::Result<()> {
+        let grid: Grid<char> = WORDS.parse()?;
+        let cross: Grid<char> = "M.S\n.A.\nM.S".parse()?;
+        let wildcard = |s: &char, c: &char| *s == '.' || s == c;
+
+        // The 8 transforms of the cross only give 4 different stamps.
+        let matches = grid.find_stamp(&cross, &Transform::ALL, wildcard);
+        assert_eq!(matches.len(), 9);
+        assert_eq!(
+            matches
+                .iter()
+                .filter(|m| m.transform() == Transform::Identity)
+                .count(),
+            2
+        );
+        assert!(matches
+            .iter()
+            .all(|m| m.transform() != Transform::Transpose
+                && m.transform() != Transform::FlipVertical));
+
+        // Empty stamps never match.
+        let empty = Grid::<char>::try_from((vec![], 3))?;
+        assert!(grid
+            .find_stamp(&empty, &Transform::ALL, wildcard)
+            .is_empty());
+
+        // Stamps larger than the grid never match.
+        let large = Grid::try_from((vec!['.'; 11], 11))?;
+        assert!(grid
+            .find_stamp(&large, &[Transform::Identity], wildcard)
+            .is_empty());
+        assert_eq!(
+            grid.find_stamp(&large, &[Transform::Rotate90], wildcard)
+                .len(),
+            0
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,336 @@
+//! Sparse module. An unbounded grid storing only its non-empty cells, indexed by [NPosition].
+
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    grid::{Grid, NEIGHBORS4, NEIGHBORS8},
+    nposition::NPosition,
+};
+
+/// An unbounded two dimension grid, storing only the cells that have been inserted.
+/// Coordinates can be negative, and the bounding box of the cells is kept up to date.
+/// Once converted to a dense [Grid], the lowest `y` is the first row, like for a [Grid].
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<NPosition, T>,
+    bounds: Option<(NPosition, NPosition)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> FromIterator<(NPosition, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (NPosition, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
+
+/// Renders the bounding box of the cells, empty cells being drawn as `.`.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.map_to_grid(|cell| cell.map_or_else(|| ".".to_string(), T::to_string))
+            .0
+            .display()
+            .fmt(f)
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty SparseGrid.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieves the number of cells stored.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Tells if no cell is stored.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Retrieves the top left and bottom right corners of the smallest rectangle containing every cell,
+    /// ie. the minimum and maximum coordinates. `None` is returned if the SparseGrid is empty.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::SparseGrid, nposition::NPosition};
+    ///
+    ///    let mut grid = SparseGrid::new();
+    ///    grid.insert(NPosition::new(-2, 3), 'a');
+    ///    grid.insert(NPosition::new(4, -1), 'b');
+    ///    assert_eq!(grid.bounds(), Some((NPosition::new(-2, -1), NPosition::new(4, 3))));
+    ///    grid.remove(&NPosition::new(4, -1));
+    ///    assert_eq!(grid.bounds(), Some((NPosition::new(-2, 3), NPosition::new(-2, 3))));
+    /// ```
+    pub fn bounds(&self) -> Option<(NPosition, NPosition)> {
+        self.bounds
+    }
+
+    /// Private method growing the bounding box to contain `pos`.
+    fn grow(&mut self, pos: &NPosition) {
+        self.bounds = Some(match self.bounds {
+            None => (*pos, *pos),
+            Some((min, max)) => (
+                NPosition::new(min.x().min(pos.x()), min.y().min(pos.y())),
+                NPosition::new(max.x().max(pos.x()), max.y().max(pos.y())),
+            ),
+        });
+    }
+
+    /// Retrieves a reference over the cell at `pos`, if any.
+    pub fn get(&self, pos: &NPosition) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    /// Retrieves a mutable reference over the cell at `pos`, if any.
+    pub fn get_mut(&mut self, pos: &NPosition) -> Option<&mut T> {
+        self.cells.get_mut(pos)
+    }
+
+    /// Retrieves a mutable reference over the cell at `pos`, inserting the result of `f` first if there is none.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::SparseGrid, nposition::NPosition};
+    ///
+    ///    let mut visits = SparseGrid::new();
+    ///    for pos in [NPosition::new(0, 0), NPosition::new(-1, 0), NPosition::new(0, 0)] {
+    ///        *visits.get_or_insert_with(pos, || 0) += 1;
+    ///    }
+    ///    assert_eq!(visits.get(&NPosition::new(0, 0)), Some(&2));
+    ///    assert_eq!(visits.len(), 2);
+    /// ```
+    pub fn get_or_insert_with(&mut self, pos: NPosition, f: impl FnOnce() -> T) -> &mut T {
+        self.grow(&pos);
+        self.cells.entry(pos).or_insert_with(f)
+    }
+
+    /// Inserts `value` at `pos`, returning the previous value of the cell if any.
+    pub fn insert(&mut self, pos: NPosition, value: T) -> Option<T> {
+        self.grow(&pos);
+        self.cells.insert(pos, value)
+    }
+
+    /// Removes the cell at `pos`, returning its value if any. The bounding box shrinks if needed.
+    pub fn remove(&mut self, pos: &NPosition) -> Option<T> {
+        let value = self.cells.remove(pos)?;
+
+        if let Some((min, max)) = self.bounds {
+            if [min.x(), max.x()].contains(&pos.x()) || [min.y(), max.y()].contains(&pos.y()) {
+                self.bounds = None;
+                let positions = self.cells.keys().copied().collect::<Vec<_>>();
+                positions.iter().for_each(|pos| self.grow(pos));
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Yields an [Iterator] of positions and references over every stored cell, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&NPosition, &T)> {
+        self.cells.iter()
+    }
+
+    /// Yields an [Iterator] of positions and references over the stored cells at `offsets` of `pos`,
+    /// in the order of `offsets`. An offset is a `(dx, dy)` tuple.
+    pub fn neighbors_with_positions<'a>(
+        &'a self,
+        pos: &NPosition,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (NPosition, &'a T)> {
+        let pos = *pos;
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let neighbor = NPosition::new(pos.x().checked_add(dx)?, pos.y().checked_add(dy)?);
+            self.cells.get(&neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    /// Yields an [Iterator] of positions and references over the stored orthogonal neighbours of `pos`,
+    /// in row-major order, like [Grid::neighbors4].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::SparseGrid, nposition::NPosition};
+    ///
+    ///    let grid = [(NPosition::new(-1, 0), 'a'), (NPosition::new(-1, -1), 'b'), (NPosition::new(0, 1), 'c')]
+    ///        .into_iter()
+    ///        .collect::<SparseGrid<_>>();
+    ///    assert_eq!(grid.neighbors4(&NPosition::new(0, 0)).collect::<Vec<_>>(), vec![
+    ///            (NPosition::new(-1, 0), &'a'), (NPosition::new(0, 1), &'c')
+    ///        ]);
+    ///    assert_eq!(grid.neighbors8(&NPosition::new(0, 0)).count(), 3);
+    /// ```
+    pub fn neighbors4(&self, pos: &NPosition) -> impl Iterator<Item = (NPosition, &T)> {
+        self.neighbors_with_positions(pos, &NEIGHBORS4)
+    }
+
+    /// Yields an [Iterator] of positions and references over the stored surrounding neighbours of `pos`,
+    /// diagonals included, in row-major order, like [Grid::neighbors8].
+    pub fn neighbors8(&self, pos: &NPosition) -> impl Iterator<Item = (NPosition, &T)> {
+        self.neighbors_with_positions(pos, &NEIGHBORS8)
+    }
+
+    /// Converts the bounding box into a dense [Grid], mapping every cell, stored or not, with `f`.
+    /// Also returns the offset of the [Grid], ie. the [NPosition] of its `(0, 0)` cell: the cell at `pos` is at
+    /// `pos.relative_to(&offset)` in the [Grid]. An empty SparseGrid gives an empty [Grid].
+    /// Panics if the bounding box spans the whole `isize` range, as its size can't be represented.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::SparseGrid, nposition::NPosition};
+    ///
+    ///    let grid = [(NPosition::new(-1, -1), 1), (NPosition::new(1, 0), 2)].into_iter().collect::<SparseGrid<_>>();
+    ///    let (dense, offset) = grid.map_to_grid(|cell| cell.copied().unwrap_or_default());
+    ///    assert_eq!(offset, NPosition::new(-1, -1));
+    ///    assert_eq!(dense.iter().collect::<Vec<_>>(), vec![&1, &0, &0, &0, &0, &2]);
+    ///    assert_eq!(dense.get(&NPosition::new(1, 0).relative_to(&offset).unwrap()).unwrap(), &2);
+    /// ```
+    pub fn map_to_grid<U>(&self, mut f: impl FnMut(Option<&T>) -> U) -> (Grid<U>, NPosition) {
+        let Some((min, max)) = self.bounds else {
+            return (
+                Grid {
+                    grid: Vec::new(),
+                    nb_row: 0,
+                    nb_col: 0,
+                },
+                NPosition::default(),
+            );
+        };
+
+        let span = |min: isize, max: isize| {
+            max.abs_diff(min)
+                .checked_add(1)
+                .expect("the bounding box spans the whole isize range and can't fit in a Grid")
+        };
+        let (nb_row, nb_col) = (span(min.y(), max.y()), span(min.x(), max.x()));
+
+        let grid = (min.y()..=max.y())
+            .flat_map(|y| (min.x()..=max.x()).map(move |x| NPosition::new(x, y)))
+            .map(|pos| f(self.cells.get(&pos)))
+            .collect();
+
+        (
+            Grid {
+                grid,
+                nb_row,
+                nb_col,
+            },
+            min,
+        )
+    }
+
+    /// Converts the bounding box into a dense [Grid], cells that aren't stored being `fill`. See [SparseGrid::map_to_grid].
+    pub fn to_grid(&self, fill: T) -> (Grid<T>, NPosition)
+    where
+        T: Clone,
+    {
+        self.map_to_grid(|cell| cell.unwrap_or(&fill).clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{grid::SparseGrid, nposition::NPosition, position::Position};
+
+    #[test]
+    fn insert_remove() {
+        let mut grid = SparseGrid::new();
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+
+        assert_eq!(grid.insert(NPosition::new(0, 0), 1), None);
+        assert_eq!(grid.insert(NPosition::new(-3, 2), 2), None);
+        assert_eq!(grid.insert(NPosition::new(0, 0), 3), Some(1));
+        *grid.get_or_insert_with(NPosition::new(5, -7), || 0) += 4;
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.get(&NPosition::new(5, -7)), Some(&4));
+        assert_eq!(grid.get(&NPosition::new(5, 7)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some((NPosition::new(-3, -7), NPosition::new(5, 2)))
+        );
+
+        // Removing an inner cell doesn't change the bounds.
+        grid.insert(NPosition::new(1, 1), 5);
+        assert_eq!(grid.remove(&NPosition::new(1, 1)), Some(5));
+        assert_eq!(
+            grid.bounds(),
+            Some((NPosition::new(-3, -7), NPosition::new(5, 2)))
+        );
+        assert_eq!(grid.remove(&NPosition::new(5, -7)), Some(4));
+        assert_eq!(
+            grid.bounds(),
+            Some((NPosition::new(-3, 0), NPosition::new(0, 2)))
+        );
+        assert_eq!(grid.remove(&NPosition::new(5, -7)), None);
+        grid.remove(&NPosition::new(0, 0));
+        grid.remove(&NPosition::new(-3, 2));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn to_grid() -> anyhow::Result<()> {
+        let grid = [
+            (NPosition::new(-2, -1), '#'),
+            (NPosition::new(0, 0), 's'),
+            (NPosition::new(1, 1), '#'),
+        ]
+        .into_iter()
+        .collect::<SparseGrid<_>>();
+
+        let (dense, offset) = grid.to_grid(' ');
+        assert_eq!(offset, NPosition::new(-2, -1));
+        assert_eq!((dense.nb_row(), dense.nb_col()), (3, 4));
+        assert_eq!(dense.get(&Position::new(2, 1))?, &'s');
+        assert_eq!(grid.to_string(), "#...\n..s.\n...#\n");
+
+        let (empty, _) = SparseGrid::<char>::new().to_grid('.');
+        assert_eq!((empty.nb_row(), empty.nb_col()), (0, 0));
+        assert_eq!(SparseGrid::<char>::new().to_string(), "");
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "whole isize range")]
+    fn to_grid_full_range() {
+        let grid = [
+            (NPosition::new(isize::MIN, 0), 1),
+            (NPosition::new(isize::MAX, 0), 2),
+        ]
+        .into_iter()
+        .collect::<SparseGrid<_>>();
+        grid.to_grid(0);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = [
+            (NPosition::new(isize::MAX, 0), 1),
+            (NPosition::new(isize::MAX - 1, 1), 2),
+            (NPosition::new(isize::MAX - 1, -1), 3),
+        ]
+        .into_iter()
+        .collect::<SparseGrid<_>>();
+
+        // Overflowing neighbours are skipped.
+        assert_eq!(
+            grid.neighbors8(&NPosition::new(isize::MAX, 0))
+                .collect::<Vec<_>>(),
+            vec![
+                (NPosition::new(isize::MAX - 1, -1), &3),
+                (NPosition::new(isize::MAX - 1, 1), &2)
+            ]
+        );
+        assert_eq!(grid.neighbors4(&NPosition::new(isize::MAX, 0)).count(), 0);
+    }
+}
@@ -0,0 +1,559 @@
+//! Transform module. Rotations, reflections, tiling and row or column insertion/removal of a [Grid].
+
+use crate::{
+    error::{LibError, LibResult},
+    grid::{Error, Grid},
+    position::Position,
+};
+
+/// One of the 8 ways to rotate and/or mirror a [Grid]. Rotations are clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    /// Leaves the [Grid] as is.
+    Identity,
+    /// Rotates by 90 degrees, clockwise.
+    Rotate90,
+    /// Rotates by 180 degrees.
+    Rotate180,
+    /// Rotates by 270 degrees clockwise, ie. 90 degrees counterclockwise.
+    Rotate270,
+    /// Swaps rows and columns, mirroring along the top left to bottom right diagonal.
+    Transpose,
+    /// Mirrors along the top right to bottom left diagonal.
+    AntiTranspose,
+    /// Mirrors left to right, ie. reverses every row.
+    FlipHorizontal,
+    /// Mirrors top to bottom, ie. reverses every column.
+    FlipVertical,
+}
+
+impl Transform {
+    /// Every transform, eg. to try all orientations of a tile.
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::Rotate90,
+        Transform::Rotate180,
+        Transform::Rotate270,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+    ];
+
+    /// Tells if the transform swaps the number of rows and columns.
+    pub fn swaps_axes(&self) -> bool {
+        matches!(
+            self,
+            Transform::Rotate90
+                | Transform::Rotate270
+                | Transform::Transpose
+                | Transform::AntiTranspose
+        )
+    }
+
+    /// Private method retrieving the position, in a source grid of `nb_row` rows and `nb_col` columns,
+    /// of the cell displayed at `pos` once transformed. `pos` must be inside the transformed grid.
+    fn source(&self, pos: &Position, nb_row: usize, nb_col: usize) -> Position {
+        let (x, y) = (pos.x(), pos.y());
+        let (x, y) = match self {
+            Transform::Identity => (x, y),
+            Transform::Rotate90 => (y, nb_row - 1 - x),
+            Transform::Rotate180 => (nb_col - 1 - x, nb_row - 1 - y),
+            Transform::Rotate270 => (nb_col - 1 - y, x),
+            Transform::Transpose => (y, x),
+            Transform::AntiTranspose => (nb_col - 1 - y, nb_row - 1 - x),
+            Transform::FlipHorizontal => (nb_col - 1 - x, y),
+            Transform::FlipVertical => (x, nb_row - 1 - y),
+        };
+        Position::new(x, y)
+    }
+}
+
+/// A zero-copy view of a [Grid] through a [Transform], see [Grid::transformed].
+/// Positions are the ones of the transformed grid.
+#[derive(Debug, Clone, Copy)]
+pub struct TransformView<'a, T> {
+    grid: &'a Grid<T>,
+    transform: Transform,
+}
+
+impl<'a, T> TransformView<'a, T> {
+    /// Retrieves the number of rows of the transformed [Grid].
+    pub fn nb_row(&self) -> usize {
+        if self.transform.swaps_axes() {
+            self.grid.nb_col
+        } else {
+            self.grid.nb_row
+        }
+    }
+
+    /// Retrieves the number of columns of the transformed [Grid].
+    pub fn nb_col(&self) -> usize {
+        if self.transform.swaps_axes() {
+            self.grid.nb_row
+        } else {
+            self.grid.nb_col
+        }
+    }
+
+    /// Retrieves a reference over the cell at `pos` of the transformed [Grid].
+    /// Error is raised if `pos` is out of the transformed [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::{Grid, Transform}, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    let view = grid.transformed(Transform::Rotate90);
+    ///    assert_eq!(view.get(&Position::new(0, 0)).unwrap(), &4);
+    ///    assert!(view.get(&Position::new(0, 3)).is_err());
+    /// ```
+    pub fn get(&self, pos: &Position) -> LibResult<&'a T> {
+        if pos.x() >= self.nb_col() || pos.y() >= self.nb_row() {
+            return Err(LibError::from(Error::InvalidPosition(*pos)));
+        }
+        let source = self
+            .transform
+            .source(pos, self.grid.nb_row, self.grid.nb_col);
+        Ok(&self.grid.grid[source.y() * self.grid.nb_col + source.x()])
+    }
+
+    /// Yields an [Iterator] of positions and references over the transformed [Grid], row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &'a T)> + '_ {
+        let (nb_row, nb_col) = (self.grid.nb_row, self.grid.nb_col);
+        (0..self.nb_row())
+            .flat_map(move |y| (0..self.nb_col()).map(move |x| Position::new(x, y)))
+            .map(move |pos| {
+                let source = self.transform.source(&pos, nb_row, nb_col);
+                (pos, &self.grid.grid[source.y() * nb_col + source.x()])
+            })
+    }
+
+    /// Yields an [Iterator] of references over the transformed [Grid], row by row.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::{Grid, Transform};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    let view = grid.transformed(Transform::Transpose);
+    ///    assert_eq!((view.nb_row(), view.nb_col()), (3, 2));
+    ///    assert_eq!(view.iter().collect::<Vec<_>>(), vec![&1, &4, &2, &5, &3, &6]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.enumerate().map(|(_, v)| v)
+    }
+
+    /// Copies the transformed [Grid].
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            grid: self.iter().cloned().collect(),
+            nb_row: self.nb_row(),
+            nb_col: self.nb_col(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a zero-copy view of the [Grid] through `transform`.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::{Grid, Transform}, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    let view = grid.transformed(Transform::FlipHorizontal);
+    ///    assert_eq!(view.iter().collect::<Vec<_>>(), vec![&3, &2, &1, &6, &5, &4]);
+    /// ```
+    pub fn transformed(&self, transform: Transform) -> TransformView<'_, T> {
+        TransformView {
+            grid: self,
+            transform,
+        }
+    }
+
+    /// Creates a new [Grid] transformed by `transform`.
+    pub fn transform(&self, transform: Transform) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(transform).to_grid()
+    }
+
+    /// Creates a new [Grid] with rows and columns swapped.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    let transposed = grid.transpose();
+    ///    assert_eq!((transposed.nb_row(), transposed.nb_col()), (3, 2));
+    ///    assert_eq!(transposed.iter().collect::<Vec<_>>(), vec![&1, &4, &2, &5, &3, &6]);
+    /// ```
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transform(Transform::Transpose)
+    }
+
+    /// Creates a new [Grid] rotated by 90 degrees, clockwise.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    assert_eq!(grid.rotate90().iter().collect::<Vec<_>>(), vec![&4, &1, &5, &2, &6, &3]);
+    /// ```
+    pub fn rotate90(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transform(Transform::Rotate90)
+    }
+
+    /// Creates a new [Grid] rotated by 180 degrees.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    assert_eq!(grid.rotate180().iter().collect::<Vec<_>>(), vec![&6, &5, &4, &3, &2, &1]);
+    /// ```
+    pub fn rotate180(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transform(Transform::Rotate180)
+    }
+
+    /// Creates a new [Grid] rotated by 270 degrees clockwise, ie. 90 degrees counterclockwise.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    assert_eq!(grid.rotate270().iter().collect::<Vec<_>>(), vec![&3, &6, &2, &5, &1, &4]);
+    /// ```
+    pub fn rotate270(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transform(Transform::Rotate270)
+    }
+
+    /// Creates a new [Grid] mirrored left to right.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    assert_eq!(grid.flip_horizontal().iter().collect::<Vec<_>>(), vec![&3, &2, &1, &6, &5, &4]);
+    /// ```
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transform(Transform::FlipHorizontal)
+    }
+
+    /// Creates a new [Grid] mirrored top to bottom.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    assert_eq!(grid.flip_vertical().iter().collect::<Vec<_>>(), vec![&4, &5, &6, &1, &2, &3]);
+    /// ```
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transform(Transform::FlipVertical)
+    }
+
+    /// Creates a new [Grid] made of `nb_row_tiles` by `nb_col_tiles` copies of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2], 2)).unwrap();
+    ///    let tiled = grid.tile(2, 3);
+    ///    assert_eq!((tiled.nb_row(), tiled.nb_col()), (2, 6));
+    ///    assert_eq!(tiled.iter().collect::<Vec<_>>(), vec![&1, &2, &1, &2, &1, &2, &1, &2, &1, &2, &1, &2]);
+    /// ```
+    pub fn tile(&self, nb_row_tiles: usize, nb_col_tiles: usize) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut grid = Vec::with_capacity(self.grid.len() * nb_row_tiles * nb_col_tiles);
+        for _ in 0..nb_row_tiles {
+            for row in self.grid.chunks_exact(self.nb_col.max(1)) {
+                for _ in 0..nb_col_tiles {
+                    grid.extend_from_slice(row);
+                }
+            }
+        }
+
+        Grid {
+            grid,
+            nb_row: self.nb_row * nb_row_tiles,
+            nb_col: self.nb_col * nb_col_tiles,
+        }
+    }
+
+    /// Inserts `values` as a new row at index `row`, shifting the following rows down.
+    /// Error is raised if `row` is greater than the number of rows, or if there isn't exactly one value per column.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let mut grid = Grid::try_from((vec![1, 2, 3, 4], 2)).unwrap();
+    ///    grid.insert_row(1, [0, 0]).unwrap();
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &2, &0, &0, &3, &4]);
+    ///    assert!(grid.insert_row(4, [0, 0]).is_err());
+    ///    assert!(grid.insert_row(0, [0]).is_err());
+    /// ```
+    pub fn insert_row(&mut self, row: usize, values: impl IntoIterator<Item = T>) -> LibResult<()> {
+        if row > self.nb_row {
+            return Err(LibError::from(Error::InvalidRow(row)));
+        }
+        let values = values.into_iter().collect::<Vec<_>>();
+        if values.len() != self.nb_col {
+            return Err(LibError::from(Error::RaggedRow {
+                row,
+                expected: self.nb_col,
+                found: values.len(),
+            }));
+        }
+
+        let index = row * self.nb_col;
+        self.grid.splice(index..index, values);
+        self.nb_row += 1;
+
+        Ok(())
+    }
+
+    /// Inserts `values` as a new column at index `col`, shifting the following columns right.
+    /// Error is raised if `col` is greater than the number of columns, or if there isn't exactly one value per row.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let mut grid = Grid::try_from((vec![1, 2, 3, 4], 2)).unwrap();
+    ///    grid.insert_col(2, [0, 0]).unwrap();
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &2, &0, &3, &4, &0]);
+    ///    assert!(grid.insert_col(4, [0, 0]).is_err());
+    ///    assert!(grid.insert_col(0, [0, 0, 0]).is_err());
+    /// ```
+    pub fn insert_col(&mut self, col: usize, values: impl IntoIterator<Item = T>) -> LibResult<()> {
+        if col > self.nb_col {
+            return Err(LibError::from(Error::InvalidCol(col)));
+        }
+        let values = values.into_iter().collect::<Vec<_>>();
+        if values.len() != self.nb_row {
+            return Err(LibError::from(Error::RaggedCol {
+                col,
+                expected: self.nb_row,
+                found: values.len(),
+            }));
+        }
+
+        let mut cells = std::mem::take(&mut self.grid).into_iter();
+        let mut grid = Vec::with_capacity(cells.len() + values.len());
+        for value in values {
+            grid.extend(cells.by_ref().take(col));
+            grid.push(value);
+            grid.extend(cells.by_ref().take(self.nb_col - col));
+        }
+        self.grid = grid;
+        self.nb_col += 1;
+
+        Ok(())
+    }
+
+    /// Removes the row at index `row`, shifting the following rows up, and returns its values.
+    /// Error is raised if `row` is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let mut grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 2)).unwrap();
+    ///    assert_eq!(grid.remove_row(1).unwrap(), vec![3, 4]);
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &2, &5, &6]);
+    ///    assert!(grid.remove_row(2).is_err());
+    /// ```
+    pub fn remove_row(&mut self, row: usize) -> LibResult<Vec<T>> {
+        if row >= self.nb_row {
+            return Err(LibError::from(Error::InvalidRow(row)));
+        }
+
+        let index = row * self.nb_col;
+        let values = self.grid.drain(index..index + self.nb_col).collect();
+        self.nb_row -= 1;
+
+        Ok(values)
+    }
+
+    /// Removes the column at index `col`, shifting the following columns left, and returns its values.
+    /// Error is raised if `col` is out of the [Grid].
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let mut grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    assert_eq!(grid.remove_col(0).unwrap(), vec![1, 4]);
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&2, &3, &5, &6]);
+    ///    assert!(grid.remove_col(2).is_err());
+    /// ```
+    pub fn remove_col(&mut self, col: usize) -> LibResult<Vec<T>> {
+        if col >= self.nb_col {
+            return Err(LibError::from(Error::InvalidCol(col)));
+        }
+
+        let mut values = Vec::with_capacity(self.nb_row);
+        let mut grid = Vec::with_capacity(self.grid.len() - self.nb_row);
+        for (index, value) in std::mem::take(&mut self.grid).into_iter().enumerate() {
+            if index % self.nb_col == col {
+                values.push(value);
+            } else {
+                grid.push(value);
+            }
+        }
+        self.grid = grid;
+        self.nb_col -= 1;
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::{Error, Grid, Transform},
+        position::Position,
+    };
+
+    /// Renders a grid of chars, one line per row.
+    fn rows(grid: &Grid<char>) -> Vec<String> {
+        grid.display()
+            .to_string()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn transform() -> anyhow::Result<()> {
+        let g: Grid<char> = "abcd\nefgh\nijkl".parse()?;
+
+        assert_eq!(rows(&g.transpose()), vec!["aei", "bfj", "cgk", "dhl"]);
+        assert_eq!(rows(&g.rotate90()), vec!["iea", "jfb", "kgc", "lhd"]);
+        assert_eq!(rows(&g.rotate180()), vec!["lkji", "hgfe", "dcba"]);
+        assert_eq!(rows(&g.rotate270()), vec!["dhl", "cgk", "bfj", "aei"]);
+        assert_eq!(rows(&g.flip_horizontal()), vec!["dcba", "hgfe", "lkji"]);
+        assert_eq!(rows(&g.flip_vertical()), vec!["ijkl", "efgh", "abcd"]);
+        assert_eq!(
+            rows(&g.transform(Transform::AntiTranspose)),
+            vec!["lhd", "kgc", "jfb", "iea"]
+        );
+        assert_eq!(rows(&g.transform(Transform::Identity)), rows(&g));
+
+        // Four quarter turns lead back to the original grid.
+        let turned = g.rotate90().rotate90().rotate90().rotate90();
+        assert_eq!(rows(&turned), rows(&g));
+        // Every transform is distinct on a grid without symmetry.
+        for (i, a) in Transform::ALL.iter().enumerate() {
+            for b in &Transform::ALL[i + 1..] {
+                assert_ne!(rows(&g.transform(*a)), rows(&g.transform(*b)));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn transformed() -> anyhow::Result<()> {
+        let g: Grid<char> = "abcd\nefgh\nijkl".parse()?;
+
+        for transform in Transform::ALL {
+            let view = g.transformed(transform);
+            let grid = g.transform(transform);
+            assert_eq!(
+                (view.nb_row(), view.nb_col()),
+                (grid.nb_row(), grid.nb_col())
+            );
+            assert_eq!(
+                view.enumerate().collect::<Vec<_>>(),
+                grid.enumerate().collect::<Vec<_>>()
+            );
+        }
+
+        let view = g.transformed(Transform::Rotate270);
+        assert_eq!(view.get(&Position::new(2, 0))?, &'l');
+        assert!(matches!(
+            view.get(&Position::new(3, 0)),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tile() -> anyhow::Result<()> {
+        let g: Grid<char> = "ab\ncd\nef".parse()?;
+
+        assert_eq!(
+            rows(&g.tile(2, 3)),
+            vec!["ababab", "cdcdcd", "efefef", "ababab", "cdcdcd", "efefef"]
+        );
+        let empty = g.tile(0, 3);
+        assert_eq!((empty.nb_row(), empty.nb_col()), (0, 6));
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_remove() -> anyhow::Result<()> {
+        let mut g: Grid<char> = "abc\ndef".parse()?;
+
+        g.insert_row(2, "ghi".chars())?;
+        g.insert_col(0, "xyz".chars())?;
+        g.insert_col(2, "123".chars())?;
+        assert_eq!(rows(&g), vec!["xa1bc", "yd2ef", "zg3hi"]);
+        assert_eq!((g.nb_row(), g.nb_col()), (3, 5));
+
+        assert_eq!(g.remove_col(4)?, vec!['c', 'f', 'i']);
+        assert_eq!(g.remove_row(0)?, vec!['x', 'a', '1', 'b']);
+        assert_eq!(rows(&g), vec!["yd2e", "zg3h"]);
+        assert_eq!(g.get(&Position::new(3, 1))?, &'h');
+
+        assert!(matches!(
+            g.insert_row(0, "abc".chars()),
+            Err(LibError::Grid(Error::RaggedRow {
+                row: 0,
+                expected: 4,
+                found: 3
+            }))
+        ));
+        assert!(matches!(
+            g.insert_col(1, "abc".chars()),
+            Err(LibError::Grid(Error::RaggedCol {
+                col: 1,
+                expected: 2,
+                found: 3
+            }))
+        ));
+        assert!(matches!(
+            g.remove_row(2),
+            Err(LibError::Grid(Error::InvalidRow(2)))
+        ));
+        assert!(matches!(
+            g.remove_col(4),
+            Err(LibError::Grid(Error::InvalidCol(4)))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,612 @@
+//! View module. Borrowed rectangles of a [Grid], with their own local coordinates.
+
+use std::fmt::Display;
+
+use crate::{
+    error::{LibError, LibResult},
+    grid::{check_rect, Error, Grid, GridRenderer, NEIGHBORS4, NEIGHBORS8},
+    position::Position,
+};
+
+/// A borrowed rectangle of a [Grid], see [Grid::view]. Positions are local to the view,
+/// `(0, 0)` being its top left corner.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    top_left: Position,
+    nb_row: usize,
+    nb_col: usize,
+}
+
+impl<'a, T> Clone for GridView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for GridView<'a, T> {}
+
+/// Two views are equal if they have the same size and the same cells, wherever they are.
+impl<'a, 'b, T: PartialEq> PartialEq<GridView<'b, T>> for GridView<'a, T> {
+    fn eq(&self, other: &GridView<'b, T>) -> bool {
+        (self.nb_row, self.nb_col) == (other.nb_row, other.nb_col) && self.iter().eq(other.iter())
+    }
+}
+
+/// Renders the view with the [Display] implementation of its cells, see [GridView::display].
+impl<'a, T: Display> Display for GridView<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.display().fmt(f)
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Retrieves the number of rows of the view.
+    pub fn nb_row(&self) -> usize {
+        self.nb_row
+    }
+
+    /// Retrieves the number of columns of the view.
+    pub fn nb_col(&self) -> usize {
+        self.nb_col
+    }
+
+    /// Retrieves the position, in the [Grid], of the top left corner of the view.
+    pub fn top_left(&self) -> Position {
+        self.top_left
+    }
+
+    /// Converts a position local to the view into a position of the [Grid].
+    /// Error is raised if `pos` is out of the view.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let view = grid.view(Position::new(1, 1), Position::new(2, 2)).unwrap();
+    ///    assert_eq!(view.grid_position(&Position::new(1, 0)).unwrap(), Position::new(2, 1));
+    ///    assert!(view.grid_position(&Position::new(2, 0)).is_err());
+    /// ```
+    pub fn grid_position(&self, pos: &Position) -> LibResult<Position> {
+        if pos.x() >= self.nb_col || pos.y() >= self.nb_row {
+            return Err(LibError::from(Error::InvalidPosition(*pos)));
+        }
+        Ok(Position::new(
+            self.top_left.x() + pos.x(),
+            self.top_left.y() + pos.y(),
+        ))
+    }
+
+    /// Private method retrieving the index, in the [Grid], of a valid local position.
+    fn index(&self, x: usize, y: usize) -> usize {
+        (self.top_left.y() + y) * self.grid.nb_col + self.top_left.x() + x
+    }
+
+    /// Retrieves a reference to data at a local position. Error is raised if the position is out of the view.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let view = grid.view(Position::new(1, 1), Position::new(2, 2)).unwrap();
+    ///    assert_eq!(view.get(&Position::new(0, 0)).unwrap(), &5);
+    ///    assert!(view.get(&Position::new(0, 2)).is_err());
+    /// ```
+    pub fn get(&self, pos: &Position) -> LibResult<&'a T> {
+        let grid_pos = self.grid_position(pos)?;
+        Ok(&self.grid.grid[grid_pos.y() * self.grid.nb_col + grid_pos.x()])
+    }
+
+    /// Yields an [Iterator] of references over the row `row` of the view. Error is raised if the row doesn't exist.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let view = grid.view(Position::new(1, 1), Position::new(2, 2)).unwrap();
+    ///    assert_eq!(view.iter_row(1).unwrap().collect::<Vec<_>>(), vec![&8, &9]);
+    /// ```
+    pub fn iter_row(
+        &self,
+        row: usize,
+    ) -> LibResult<impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator> {
+        if row >= self.nb_row {
+            return Err(LibError::from(Error::InvalidRow(row)));
+        }
+        let start = self.index(0, row);
+        Ok(self.grid.grid[start..start + self.nb_col].iter())
+    }
+
+    /// Yields an [Iterator] of references over the column `col` of the view. Error is raised if the column doesn't exist.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let view = grid.view(Position::new(1, 1), Position::new(2, 2)).unwrap();
+    ///    assert_eq!(view.iter_col(1).unwrap().collect::<Vec<_>>(), vec![&6, &9]);
+    /// ```
+    pub fn iter_col(
+        &self,
+        col: usize,
+    ) -> LibResult<impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator> {
+        if col >= self.nb_col {
+            return Err(LibError::from(Error::InvalidCol(col)));
+        }
+        Ok(self.grid.grid[self.index(col, 0)..]
+            .iter()
+            .step_by(self.grid.nb_col)
+            .take(self.nb_row))
+    }
+
+    /// Yields an [Iterator] of local positions and references over the whole view, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &'a T)> {
+        let view = *self;
+        (0..self.nb_row).flat_map(move |y| {
+            (0..view.nb_col).map(move |x| (Position::new(x, y), &view.grid.grid[view.index(x, y)]))
+        })
+    }
+
+    /// Yields an [Iterator] of references over the whole view, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.enumerate().map(|(_, v)| v)
+    }
+
+    /// Yields an [Iterator] of local positions and references over the cells at `offsets` of `pos`, in row-major order.
+    /// Offsets leading out of the view are skipped, even if they lead inside the [Grid].
+    /// Error is raised if `pos` is out of the view.
+    pub fn neighbors_with_positions(
+        &self,
+        pos: &Position,
+        offsets: &[(isize, isize)],
+    ) -> LibResult<impl Iterator<Item = (Position, &'a T)>> {
+        self.grid_position(pos)?;
+
+        let mut neighbors = offsets
+            .iter()
+            .filter_map(|&(dx, dy)| {
+                let x = pos
+                    .x()
+                    .checked_add_signed(dx)
+                    .filter(|&x| x < self.nb_col)?;
+                let y = pos
+                    .y()
+                    .checked_add_signed(dy)
+                    .filter(|&y| y < self.nb_row)?;
+                Some((Position::new(x, y), &self.grid.grid[self.index(x, y)]))
+            })
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable_by_key(|(pos, _)| (pos.y(), pos.x()));
+        neighbors.dedup_by_key(|(pos, _)| *pos);
+
+        Ok(neighbors.into_iter())
+    }
+
+    /// Yields an [Iterator] of local positions and references over the 4 orthogonal neighbours of `pos`,
+    /// in row-major order. Neighbours out of the view are skipped. Error is raised if `pos` is out of the view.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let view = grid.view(Position::new(1, 1), Position::new(2, 2)).unwrap();
+    ///    assert_eq!(view.neighbors4(&Position::new(0, 0)).unwrap().collect::<Vec<_>>(), vec![
+    ///            (Position::new(1, 0), &6), (Position::new(0, 1), &8)
+    ///        ]);
+    /// ```
+    pub fn neighbors4(&self, pos: &Position) -> LibResult<impl Iterator<Item = (Position, &'a T)>> {
+        self.neighbors_with_positions(pos, &NEIGHBORS4)
+    }
+
+    /// Yields an [Iterator] of local positions and references over the 8 surrounding neighbours of `pos`,
+    /// diagonals included, in row-major order. Neighbours out of the view are skipped.
+    /// Error is raised if `pos` is out of the view.
+    pub fn neighbors8(&self, pos: &Position) -> LibResult<impl Iterator<Item = (Position, &'a T)>> {
+        self.neighbors_with_positions(pos, &NEIGHBORS8)
+    }
+
+    /// Creates a view of a rectangle of this view, `top_left` and `bottom_right` being local positions.
+    /// Error is raised if the rectangle isn't valid, like [Grid::iter_rect].
+    pub fn view(&self, top_left: Position, bottom_right: Position) -> LibResult<GridView<'a, T>> {
+        check_rect(self.nb_row, self.nb_col, &top_left, &bottom_right)?;
+
+        Ok(GridView {
+            grid: self.grid,
+            top_left: Position::new(
+                self.top_left.x() + top_left.x(),
+                self.top_left.y() + top_left.y(),
+            ),
+            nb_row: bottom_right.y() - top_left.y() + 1,
+            nb_col: bottom_right.x() - top_left.x() + 1,
+        })
+    }
+
+    /// Copies the view into a new [Grid].
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            grid: self.iter().cloned().collect(),
+            nb_row: self.nb_row,
+            nb_col: self.nb_col,
+        }
+    }
+
+    /// Creates a [GridRenderer] of the view, rendering every cell with `cell`. See [Grid::renderer].
+    pub fn renderer(&self, cell: impl Fn(&T) -> String + 'a) -> GridRenderer<'a, T> {
+        GridRenderer::from_view(*self, cell)
+    }
+
+    /// Creates a [GridRenderer] of the view, rendering every cell with its [Display] implementation. See [Grid::display].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+    ///    let view = grid.view(Position::new(1, 0), Position::new(2, 1)).unwrap();
+    ///    assert_eq!(view.display().border(true).to_string(), "----\n|bc|\n|ef|\n----\n");
+    /// ```
+    pub fn display(&self) -> GridRenderer<'a, T>
+    where
+        T: Display,
+    {
+        self.renderer(T::to_string)
+    }
+}
+
+/// A mutably borrowed rectangle of a [Grid], see [Grid::view_mut]. Positions are local to the view,
+/// `(0, 0)` being its top left corner.
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    top_left: Position,
+    nb_row: usize,
+    nb_col: usize,
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    /// Borrows the view immutably, giving access to every [GridView] method.
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self.grid,
+            top_left: self.top_left,
+            nb_row: self.nb_row,
+            nb_col: self.nb_col,
+        }
+    }
+
+    /// Retrieves the number of rows of the view.
+    pub fn nb_row(&self) -> usize {
+        self.nb_row
+    }
+
+    /// Retrieves the number of columns of the view.
+    pub fn nb_col(&self) -> usize {
+        self.nb_col
+    }
+
+    /// Retrieves a reference to data at a local position. Error is raised if the position is out of the view.
+    pub fn get(&self, pos: &Position) -> LibResult<&T> {
+        let grid_pos = self.as_view().grid_position(pos)?;
+        Ok(&self.grid.grid[grid_pos.y() * self.grid.nb_col + grid_pos.x()])
+    }
+
+    /// Retrieves a mutable reference to data at a local position. Error is raised if the position is out of the view.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let mut view = grid.view_mut(Position::new(1, 1), Position::new(2, 2)).unwrap();
+    ///    *view.get_mut(&Position::new(1, 1)).unwrap() = 0;
+    ///    assert!(view.get_mut(&Position::new(2, 1)).is_err());
+    ///    assert_eq!(grid.get(&Position::new(2, 2)).unwrap(), &0);
+    /// ```
+    pub fn get_mut(&mut self, pos: &Position) -> LibResult<&mut T> {
+        let grid_pos = self.as_view().grid_position(pos)?;
+        Ok(&mut self.grid.grid[grid_pos.y() * self.grid.nb_col + grid_pos.x()])
+    }
+
+    /// Yields an [Iterator] of mutable references over the row `row` of the view. Error is raised if the row doesn't exist.
+    pub fn iter_row_mut(
+        &mut self,
+        row: usize,
+    ) -> LibResult<impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator> {
+        if row >= self.nb_row {
+            return Err(LibError::from(Error::InvalidRow(row)));
+        }
+        let start = self.as_view().index(0, row);
+        Ok(self.grid.grid[start..start + self.nb_col].iter_mut())
+    }
+
+    /// Yields an [Iterator] of mutable references over the column `col` of the view.
+    /// Error is raised if the column doesn't exist.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let mut view = grid.view_mut(Position::new(1, 1), Position::new(2, 2)).unwrap();
+    ///    view.iter_col_mut(0).unwrap().for_each(|v| *v = 0);
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &0, &6, &7, &0, &9]);
+    /// ```
+    pub fn iter_col_mut(
+        &mut self,
+        col: usize,
+    ) -> LibResult<impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator> {
+        if col >= self.nb_col {
+            return Err(LibError::from(Error::InvalidCol(col)));
+        }
+        let start = self.as_view().index(col, 0);
+        Ok(self.grid.grid[start..]
+            .iter_mut()
+            .step_by(self.grid.nb_col)
+            .take(self.nb_row))
+    }
+
+    /// Yields an [Iterator] of local positions and mutable references over the whole view, row by row.
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        let (top_left, nb_row, nb_col) = (self.top_left, self.nb_row, self.nb_col);
+        let grid_nb_col = self.grid.nb_col;
+        let start = top_left.y() * grid_nb_col;
+        let end = start + nb_row * grid_nb_col;
+
+        self.grid.grid[start..end]
+            .chunks_exact_mut(grid_nb_col.max(1))
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                row.iter_mut()
+                    .skip(top_left.x())
+                    .take(nb_col)
+                    .enumerate()
+                    .map(move |(x, v)| (Position::new(x, y), v))
+            })
+    }
+
+    /// Yields an [Iterator] of mutable references over the whole view, row by row.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let mut grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    grid.view_mut(Position::new(0, 1), Position::new(1, 2)).unwrap().iter_mut().for_each(|v| *v = 0);
+    ///    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &0, &0, &6, &0, &0, &9]);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.enumerate_mut().map(|(_, v)| v)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a view of the whole [Grid].
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            top_left: Position::new(0, 0),
+            nb_row: self.nb_row,
+            nb_col: self.nb_col,
+        }
+    }
+
+    /// Creates a view of the rectangle defined by `top_left` and `bottom_right`, both included.
+    /// Error is raised if the rectangle isn't valid, like [Grid::iter_rect].
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let view = grid.view(Position::new(1, 0), Position::new(2, 1)).unwrap();
+    ///    assert_eq!((view.nb_row(), view.nb_col()), (2, 2));
+    ///    assert_eq!(view.iter().collect::<Vec<_>>(), vec![&2, &3, &5, &6]);
+    /// ```
+    pub fn view(&self, top_left: Position, bottom_right: Position) -> LibResult<GridView<'_, T>> {
+        self.as_view().view(top_left, bottom_right)
+    }
+
+    /// Creates a mutable view of the rectangle defined by `top_left` and `bottom_right`, both included.
+    /// Error is raised if the rectangle isn't valid, like [Grid::iter_rect].
+    pub fn view_mut(
+        &mut self,
+        top_left: Position,
+        bottom_right: Position,
+    ) -> LibResult<GridViewMut<'_, T>> {
+        check_rect(self.nb_row, self.nb_col, &top_left, &bottom_right)?;
+
+        Ok(GridViewMut {
+            grid: self,
+            top_left,
+            nb_row: bottom_right.y() - top_left.y() + 1,
+            nb_col: bottom_right.x() - top_left.x() + 1,
+        })
+    }
+
+    /// Yields an [Iterator] over every view of `nb_row` rows and `nb_col` columns, like [slice::windows].
+    /// Views are yielded row by row, and nothing is yielded if the [Grid] is smaller than a view.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    let sums = grid.windows(2, 2).map(|view| view.iter().sum()).collect::<Vec<i32>>();
+    ///    assert_eq!(sums, vec![12, 16]);
+    /// ```
+    pub fn windows(&self, nb_row: usize, nb_col: usize) -> impl Iterator<Item = GridView<'_, T>> {
+        let nb_y = match (nb_row, nb_col) {
+            (0, _) | (_, 0) => 0,
+            _ => (self.nb_row + 1).saturating_sub(nb_row),
+        };
+        let nb_x = (self.nb_col + 1).saturating_sub(nb_col);
+
+        (0..nb_y).flat_map(move |y| {
+            (0..nb_x).map(move |x| GridView {
+                grid: self,
+                top_left: Position::new(x, y),
+                nb_row,
+                nb_col,
+            })
+        })
+    }
+
+    /// Splits the [Grid] into sectors of `nb_row` rows and `nb_col` columns, like [slice::chunks].
+    /// Sectors are yielded row by row, the ones on the right and bottom edges being smaller if the size doesn't divide
+    /// the [Grid]'s one.
+    /// Example
+    /// ```rust
+    ///    use lib::grid::Grid;
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    let sectors = grid.sectors(2, 2).map(|view| view.iter().copied().collect()).collect::<Vec<Vec<_>>>();
+    ///    assert_eq!(sectors, vec![vec![1, 2, 4, 5], vec![3, 6]]);
+    /// ```
+    pub fn sectors(&self, nb_row: usize, nb_col: usize) -> impl Iterator<Item = GridView<'_, T>> {
+        let (step_y, step_x) = (nb_row.max(1), nb_col.max(1));
+        let nb_y = if nb_row == 0 || nb_col == 0 {
+            0
+        } else {
+            self.nb_row
+        };
+
+        (0..nb_y).step_by(step_y).flat_map(move |y| {
+            (0..self.nb_col).step_by(step_x).map(move |x| GridView {
+                grid: self,
+                top_left: Position::new(x, y),
+                nb_row: step_y.min(self.nb_row - y),
+                nb_col: step_x.min(self.nb_col - x),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::{Error, Grid},
+        position::Position,
+    };
+
+    const SAMPLE: &str = "abcde\nfghij\nklmno\npqrst";
+
+    #[test]
+    fn view() -> anyhow::Result<()> {
+        let g: Grid<char> = SAMPLE.parse()?;
+        let view = g.view(Position::new(1, 1), Position::new(3, 3))?;
+
+        assert_eq!((view.nb_row(), view.nb_col()), (3, 3));
+        assert_eq!(view.top_left(), Position::new(1, 1));
+        assert_eq!(view.get(&Position::new(2, 1))?, &'n');
+        assert_eq!(view.iter_row(2)?.rev().collect::<String>(), "srq");
+        assert_eq!(view.iter_col(0)?.collect::<String>(), "glq");
+        assert_eq!(view.iter().collect::<String>(), "ghilmnqrs");
+        assert_eq!(view.to_grid().iter().collect::<String>(), "ghilmnqrs");
+        assert!(matches!(
+            view.iter_row(3),
+            Err(LibError::Grid(Error::InvalidRow(3)))
+        ));
+        assert!(matches!(
+            view.iter_col(3),
+            Err(LibError::Grid(Error::InvalidCol(3)))
+        ));
+        assert!(matches!(
+            view.get(&Position::new(3, 0)),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+
+        // Nested view.
+        let inner = view.view(Position::new(1, 1), Position::new(2, 2))?;
+        assert_eq!(inner.iter().collect::<String>(), "mnrs");
+        assert_eq!(inner.top_left(), Position::new(2, 2));
+        assert!(view.view(Position::new(1, 1), Position::new(3, 2)).is_err());
+        assert!(matches!(
+            g.view(Position::new(2, 0), Position::new(1, 1)),
+            Err(LibError::Grid(Error::InvalidRectangle(_, _)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn neighbors() -> anyhow::Result<()> {
+        let g: Grid<char> = SAMPLE.parse()?;
+        let view = g.view(Position::new(1, 1), Position::new(3, 2))?;
+
+        // Cells of the Grid outside of the view are never yielded.
+        assert_eq!(
+            view.neighbors8(&Position::new(0, 0))?.collect::<Vec<_>>(),
+            vec![
+                (Position::new(1, 0), &'h'),
+                (Position::new(0, 1), &'l'),
+                (Position::new(1, 1), &'m')
+            ]
+        );
+        assert_eq!(
+            view.neighbors4(&Position::new(2, 1))?
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "im"
+        );
+        assert!(view.neighbors4(&Position::new(0, 2)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn compare_and_display() -> anyhow::Result<()> {
+        let g: Grid<char> = "abab\ncdcd\nabab".parse()?;
+
+        let left = g.view(Position::new(0, 0), Position::new(1, 1))?;
+        let right = g.view(Position::new(2, 0), Position::new(3, 1))?;
+        let bottom = g.view(Position::new(0, 1), Position::new(1, 2))?;
+        assert_eq!(left, right);
+        assert_ne!(left, bottom);
+        assert_eq!(left.to_string(), "ab\ncd\n");
+        assert_eq!(g.as_view().to_string(), g.display().to_string());
+
+        assert_eq!(g.windows(2, 3).count(), 4);
+        assert_eq!(g.windows(4, 1).count(), 0);
+        assert_eq!(g.windows(2, 2).filter(|w| *w == left).count(), 2);
+        let sectors = g.sectors(2, 3).collect::<Vec<_>>();
+        assert_eq!(
+            sectors
+                .iter()
+                .map(|s| (s.top_left(), s.nb_row(), s.nb_col()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Position::new(0, 0), 2, 3),
+                (Position::new(3, 0), 2, 1),
+                (Position::new(0, 2), 1, 3),
+                (Position::new(3, 2), 1, 1)
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_mut() -> anyhow::Result<()> {
+        let mut g: Grid<char> = SAMPLE.parse()?;
+        let mut view = g.view_mut(Position::new(2, 1), Position::new(4, 2))?;
+
+        assert_eq!(view.get(&Position::new(0, 0))?, &'h');
+        view.iter_row_mut(1)?.for_each(|c| *c = '#');
+        view.iter_col_mut(2)?.for_each(|c| *c = '|');
+        *view.get_mut(&Position::new(0, 0))? = '*';
+        assert_eq!(view.as_view().to_string(), "*i|\n##|\n");
+        assert_eq!(
+            view.enumerate_mut().map(|(pos, _)| pos).last(),
+            Some(Position::new(2, 1))
+        );
+        view.iter_mut()
+            .filter(|c| **c == '#')
+            .for_each(|c| *c = '.');
+        assert!(view.iter_row_mut(2).is_err());
+
+        assert_eq!(g.display().to_string(), "abcde\nfg*i|\nkl..|\npqrst\n");
+        assert!(g
+            .view_mut(Position::new(0, 0), Position::new(5, 0))
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+//! Wrapping module. A toroidal topology over a [Grid], where leaving an edge comes back from the opposite one.
+
+use crate::{
+    direction::Direction,
+    error::{LibError, LibResult},
+    grid::{Error, Grid, NEIGHBORS8},
+    nposition::NPosition,
+    position::Position,
+};
+
+/// Closure mapping a move leaving the grid to its destination, see [WrappingGrid::portal].
+type Portal<'a> = Box<dyn Fn(Position, Direction) -> Option<(Position, Direction)> + 'a>;
+
+/// Borrows a [Grid] as a torus: positions outside of it wrap around, and moves leaving an edge
+/// come back from the opposite one. Created with [Grid::wrapping].
+/// Moves follow [Position::apply_direction], ie. [Direction::Up] increases `y`.
+pub struct WrappingGrid<'a, T> {
+    grid: &'a Grid<T>,
+    portal: Option<Portal<'a>>,
+}
+
+impl<'a, T> WrappingGrid<'a, T> {
+    /// Creates a WrappingGrid over `grid`. Error is raised if `grid` is empty, as nothing can wrap on it.
+    pub fn new(grid: &'a Grid<T>) -> LibResult<Self> {
+        if grid.nb_row == 0 || grid.nb_col == 0 {
+            return Err(LibError::from(Error::EmptyGrid));
+        }
+
+        Ok(Self { grid, portal: None })
+    }
+
+    /// Sets a custom wrapping rule, for the moves of [WrappingGrid::step] leaving the grid.
+    /// `portal` receives the last position inside the grid and the direction of the move, which together identify
+    /// the edge being crossed, and returns the destination and the new direction.
+    /// When `portal` returns `None`, the move wraps to the opposite edge as usual.
+    /// Example
+    /// ```rust
+    ///    use lib::{direction::Direction, grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![0; 9], 3)).unwrap();
+    ///    // Leaving the right edge leads to the top row, moving Up.
+    ///    let wrapping = grid.wrapping().unwrap().portal(|pos, direction| match direction {
+    ///        Direction::Right => Some((Position::new(pos.y(), 0), Direction::Up)),
+    ///        _ => None,
+    ///    });
+    ///    assert_eq!(wrapping.step(Position::new(2, 1), Direction::Right).unwrap(), (Position::new(1, 0), Direction::Up));
+    ///    assert_eq!(wrapping.step(Position::new(0, 1), Direction::Left).unwrap(), (Position::new(2, 1), Direction::Left));
+    /// ```
+    pub fn portal(
+        mut self,
+        portal: impl Fn(Position, Direction) -> Option<(Position, Direction)> + 'a,
+    ) -> Self {
+        self.portal = Some(Box::new(portal));
+        self
+    }
+
+    /// Retrieves the [Grid] position corresponding to any `pos`, wrapping on both axes.
+    pub fn wrap(&self, pos: &NPosition) -> Position {
+        Position::new(
+            pos.x().rem_euclid(self.grid.nb_col as isize) as usize,
+            pos.y().rem_euclid(self.grid.nb_row as isize) as usize,
+        )
+    }
+
+    /// Private method retrieving a reference over the cell at `pos`, which must be inside of the grid.
+    fn cell(&self, pos: &Position) -> &'a T {
+        &self.grid.grid[pos.y() * self.grid.nb_col + pos.x()]
+    }
+
+    /// Retrieves a reference over the cell at `pos`, wrapping on both axes. The portal isn't used.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, nposition::NPosition};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6], 3)).unwrap();
+    ///    let wrapping = grid.wrapping().unwrap();
+    ///    assert_eq!(wrapping.get_wrapped(&NPosition::new(-1, 0)), &3);
+    ///    assert_eq!(wrapping.get_wrapped(&NPosition::new(4, 7)), &5);
+    /// ```
+    pub fn get_wrapped(&self, pos: &NPosition) -> &'a T {
+        self.cell(&self.wrap(pos))
+    }
+
+    /// Moves from `pos` towards `direction`, leaving the grid through the portal if set, or else wrapping
+    /// to the opposite edge. Returns the destination and the direction after the move, which only a portal changes.
+    /// Error is raised if `pos`, or the destination given by the portal, is outside of the grid.
+    pub fn step(&self, pos: Position, direction: Direction) -> LibResult<(Position, Direction)> {
+        if pos.x() >= self.grid.nb_col || pos.y() >= self.grid.nb_row {
+            return Err(LibError::from(Error::InvalidPosition(pos)));
+        }
+
+        let mut next = pos;
+        if next.apply_direction(&direction).is_ok()
+            && next.x() < self.grid.nb_col
+            && next.y() < self.grid.nb_row
+        {
+            return Ok((next, direction));
+        }
+
+        match self
+            .portal
+            .as_ref()
+            .and_then(|portal| portal(pos, direction))
+        {
+            Some((next, _)) if next.x() >= self.grid.nb_col || next.y() >= self.grid.nb_row => {
+                Err(LibError::from(Error::InvalidPosition(next)))
+            }
+            Some(destination) => Ok(destination),
+            None => {
+                let (dx, dy) = match direction {
+                    Direction::Up => (0, 1),
+                    Direction::Down => (0, -1),
+                    Direction::Left => (-1, 0),
+                    Direction::Right => (1, 0),
+                };
+                let next = self.wrap(&NPosition::new(
+                    pos.x() as isize + dx,
+                    pos.y() as isize + dy,
+                ));
+                Ok((next, direction))
+            }
+        }
+    }
+
+    /// Yields an [Iterator] of positions and references over the orthogonal neighbours of `pos`, in row-major order.
+    /// Neighbours across an edge are reached like with [WrappingGrid::step], portal included.
+    /// Error is raised if `pos` is outside of the grid.
+    /// Example
+    /// ```rust
+    ///    use lib::{grid::Grid, position::Position};
+    ///
+    ///    let grid = Grid::try_from((vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3)).unwrap();
+    ///    let wrapping = grid.wrapping().unwrap();
+    ///    let neighbors = wrapping.neighbors4(&Position::new(0, 0)).unwrap().map(|(_, v)| *v).collect::<Vec<_>>();
+    ///    assert_eq!(neighbors, vec![7, 3, 2, 4]);
+    /// ```
+    pub fn neighbors4(
+        &self,
+        pos: &Position,
+    ) -> LibResult<impl Iterator<Item = (Position, &'a T)> + '_> {
+        let neighbors = [
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+        ]
+        .into_iter()
+        .map(|direction| self.step(*pos, direction).map(|(next, _)| next))
+        .collect::<LibResult<Vec<_>>>()?;
+
+        Ok(neighbors.into_iter().map(|next| (next, self.cell(&next))))
+    }
+
+    /// Yields an [Iterator] of positions and references over the surrounding neighbours of `pos`,
+    /// diagonals included, in row-major order. Neighbours wrap on both axes, the portal isn't used.
+    /// Error is raised if `pos` is outside of the grid.
+    pub fn neighbors8(
+        &self,
+        pos: &Position,
+    ) -> LibResult<impl Iterator<Item = (Position, &'a T)> + '_> {
+        if pos.x() >= self.grid.nb_col || pos.y() >= self.grid.nb_row {
+            return Err(LibError::from(Error::InvalidPosition(*pos)));
+        }
+
+        let (x, y) = (pos.x() as isize, pos.y() as isize);
+        Ok(NEIGHBORS8.iter().map(move |(dx, dy)| {
+            let next = self.wrap(&NPosition::new(x + dx, y + dy));
+            (next, self.cell(&next))
+        }))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Borrows the [Grid] as a torus, see [WrappingGrid]. Error is raised if the [Grid] is empty.
+    pub fn wrapping(&self) -> LibResult<WrappingGrid<'_, T>> {
+        WrappingGrid::new(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        direction::Direction,
+        error::LibError,
+        grid::{Error, Grid},
+        nposition::NPosition,
+        position::Position,
+    };
+
+    const VALLEY: &str = "\
+.<.>
+v..^
+.>..";
+
+    #[test]
+    fn blizzards() -> anyhow::Result<()> {
+        let grid: Grid<char> = VALLEY.parse()?;
+        let wrapping = grid.wrapping()?;
+
+        // Every blizzard moves once, like a step of the blizzard basin.
+        let mut blizzards = grid
+            .enumerate()
+            .filter_map(|(pos, c)| Direction::try_from(*c).ok().map(|d| (pos, d)))
+            .collect::<Vec<_>>();
+        for (pos, direction) in blizzards.iter_mut() {
+            (*pos, *direction) = wrapping.step(*pos, *direction)?;
+        }
+        assert_eq!(
+            blizzards,
+            vec![
+                (Position::new(0, 0), Direction::Left),
+                (Position::new(0, 0), Direction::Right),
+                (Position::new(0, 0), Direction::Down),
+                (Position::new(3, 2), Direction::Up),
+                (Position::new(2, 2), Direction::Right),
+            ]
+        );
+
+        assert_eq!(wrapping.get_wrapped(&NPosition::new(-4, -3)), &'.');
+        assert_eq!(wrapping.get_wrapped(&NPosition::new(7, -2)), &'^');
+        assert_eq!(
+            wrapping
+                .neighbors8(&Position::new(0, 0))?
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "..>><^v."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors() -> anyhow::Result<()> {
+        let grid: Grid<char> = VALLEY.parse()?;
+        let wrapping = grid
+            .wrapping()?
+            .portal(|_, _| Some((Position::new(4, 0), Direction::Up)));
+
+        assert!(matches!(
+            wrapping.step(Position::new(3, 0), Direction::Right),
+            Err(LibError::Grid(Error::InvalidPosition(pos))) if pos == Position::new(4, 0)
+        ));
+        assert!(matches!(
+            wrapping.step(Position::new(0, 3), Direction::Right),
+            Err(LibError::Grid(Error::InvalidPosition(_)))
+        ));
+        assert!(wrapping.neighbors4(&Position::new(1, 1)).is_ok());
+        assert!(wrapping.neighbors8(&Position::new(4, 1)).is_err());
+
+        let empty = Grid::<char>::try_from((vec![], 3))?;
+        assert!(matches!(
+            empty.wrapping(),
+            Err(LibError::Grid(Error::EmptyGrid))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,545 @@
+//! Hex module. Hexagonal tilings, with axial/cube coordinates and a sparse map of hexagons.
+//! Hexagons are pointy-topped: every hexagon has east and west neighbours, and four diagonal ones.
+
+use std::{collections::HashMap, str::FromStr};
+
+use thiserror::Error;
+
+use crate::{
+    error::{LibError, LibResult},
+    grid::Topology,
+};
+
+/// `hex` module inner error. Must be wrapped in LibError before being raised.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Raised when a direction can't be parsed.
+    #[error("invalid hex direction `{0}`")]
+    InvalidDirection(String),
+    /// Raised when cube coordinates don't sum to zero.
+    #[error("({0}, {1}, {2}) aren't valid cube coordinates")]
+    InvalidCube(isize, isize, isize),
+}
+
+/// Easily creates LibError with the desired variant.
+impl From<Error> for LibError {
+    fn from(value: Error) -> Self {
+        LibError::Hex(value)
+    }
+}
+
+/// The six directions of a pointy-topped hexagonal tiling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    /// East, `e`.
+    East,
+    /// North east, `ne`.
+    NorthEast,
+    /// North west, `nw`.
+    NorthWest,
+    /// West, `w`.
+    West,
+    /// South west, `sw`.
+    SouthWest,
+    /// South east, `se`.
+    SouthEast,
+}
+
+/// Parses `e`, `ne`, `nw`, `w`, `sw` or `se`.
+impl FromStr for HexDirection {
+    type Err = LibError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(Self::East),
+            "ne" => Ok(Self::NorthEast),
+            "nw" => Ok(Self::NorthWest),
+            "w" => Ok(Self::West),
+            "sw" => Ok(Self::SouthWest),
+            "se" => Ok(Self::SouthEast),
+            _ => Err(LibError::from(Error::InvalidDirection(s.to_string()))),
+        }
+    }
+}
+
+impl HexDirection {
+    /// Every direction, counterclockwise from east.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::NorthEast,
+        HexDirection::NorthWest,
+        HexDirection::West,
+        HexDirection::SouthWest,
+        HexDirection::SouthEast,
+    ];
+
+    /// Retrieves the `(q, r)` axial offset of the direction. North decreases `r`.
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            HexDirection::East => (1, 0),
+            HexDirection::NorthEast => (1, -1),
+            HexDirection::NorthWest => (0, -1),
+            HexDirection::West => (-1, 0),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::SouthEast => (0, 1),
+        }
+    }
+
+    /// Parses a string of moves without delimiters, like `esenee`. Error is raised on any invalid move.
+    /// Example
+    /// ```rust
+    ///    use lib::hex::HexDirection;
+    ///
+    ///    let moves = HexDirection::parse_moves("esenee").unwrap();
+    ///    assert_eq!(moves, vec![HexDirection::East, HexDirection::SouthEast, HexDirection::NorthEast, HexDirection::East]);
+    ///    assert!(HexDirection::parse_moves("esn").is_err());
+    /// ```
+    pub fn parse_moves(s: &str) -> LibResult<Vec<HexDirection>> {
+        let mut moves = Vec::new();
+        let mut rest = s;
+        while !rest.is_empty() {
+            let len = if rest.starts_with(['n', 's']) { 2 } else { 1 };
+            let end = rest.char_indices().nth(len).map_or(rest.len(), |(i, _)| i);
+            let (token, tail) = rest.split_at(end);
+            moves.push(token.parse()?);
+            rest = tail;
+        }
+        Ok(moves)
+    }
+}
+
+/// A hexagon, in axial coordinates `q` and `r`. The third cube coordinate `s` is `-q - r`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hex {
+    q: isize,
+    r: isize,
+}
+
+impl Hex {
+    /// Creates a new Hex from its axial coordinates.
+    pub fn new(q: isize, r: isize) -> Self {
+        Self { q, r }
+    }
+
+    /// Creates a new Hex from its cube coordinates. Error is raised if they don't sum to zero.
+    pub fn from_cube(q: isize, r: isize, s: isize) -> LibResult<Self> {
+        if q + r + s == 0 {
+            Ok(Self { q, r })
+        } else {
+            Err(LibError::from(Error::InvalidCube(q, r, s)))
+        }
+    }
+
+    /// Retrieves the `q` coordinate, growing eastwards.
+    pub fn q(&self) -> isize {
+        self.q
+    }
+
+    /// Retrieves the `r` coordinate, growing south eastwards.
+    pub fn r(&self) -> isize {
+        self.r
+    }
+
+    /// Retrieves the `s` cube coordinate, growing north westwards.
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    /// Retrieves the `(q, r, s)` cube coordinates.
+    pub fn cube(&self) -> (isize, isize, isize) {
+        (self.q, self.r, self.s())
+    }
+
+    /// Retrieves the neighbour towards `direction`.
+    pub fn neighbor(&self, direction: HexDirection) -> Hex {
+        let (dq, dr) = direction.offset();
+        Hex::new(self.q + dq, self.r + dr)
+    }
+
+    /// Follows every move of `moves`, from the Hex.
+    /// Example
+    /// ```rust
+    ///    use lib::hex::{Hex, HexDirection};
+    ///
+    ///    let moves = HexDirection::parse_moves("nwwswee").unwrap();
+    ///    assert_eq!(Hex::default().walk(moves), Hex::default());
+    /// ```
+    pub fn walk(&self, moves: impl IntoIterator<Item = HexDirection>) -> Hex {
+        moves.into_iter().fold(*self, |hex, dir| hex.neighbor(dir))
+    }
+
+    /// Yields an [Iterator] over the 6 neighbours, in the order of [HexDirection::ALL].
+    pub fn neighbors(&self) -> impl Iterator<Item = Hex> {
+        let hex = *self;
+        HexDirection::ALL
+            .into_iter()
+            .map(move |dir| hex.neighbor(dir))
+    }
+
+    /// Retrieves the number of moves needed to go to `other`.
+    /// Example
+    /// ```rust
+    ///    use lib::hex::Hex;
+    ///
+    ///    assert_eq!(Hex::new(0, 0).distance(&Hex::new(3, -1)), 3);
+    ///    assert_eq!(Hex::new(-2, 1).distance(&Hex::new(2, 1)), 4);
+    /// ```
+    pub fn distance(&self, other: &Hex) -> usize {
+        let (dq, dr, ds) = (
+            self.q.abs_diff(other.q),
+            self.r.abs_diff(other.r),
+            self.s().abs_diff(other.s()),
+        );
+        dq.max(dr).max(ds)
+    }
+
+    /// Yields an [Iterator] over the hexagons at exactly `radius` moves, counterclockwise from the east one.
+    /// A radius of 0 only yields the Hex itself.
+    /// Example
+    /// ```rust
+    ///    use lib::hex::Hex;
+    ///
+    ///    let center = Hex::new(1, -2);
+    ///    assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+    ///    assert_eq!(center.ring(2).count(), 12);
+    ///    assert!(center.ring(2).all(|hex| hex.distance(&center) == 2));
+    /// ```
+    pub fn ring(&self, radius: usize) -> impl Iterator<Item = Hex> {
+        let (dq, dr) = HexDirection::East.offset();
+        let start = Hex::new(self.q + dq * radius as isize, self.r + dr * radius as isize);
+
+        // Walking each side from the east corner, counterclockwise, turns two directions further.
+        let walks = HexDirection::ALL
+            .into_iter()
+            .cycle()
+            .skip(2)
+            .take(6)
+            .flat_map(move |dir| std::iter::repeat_n(dir, radius));
+        std::iter::once(start)
+            .chain(walks.scan(start, |hex, dir| {
+                *hex = hex.neighbor(dir);
+                Some(*hex)
+            }))
+            .take((6 * radius).max(1))
+    }
+
+    /// Yields an [Iterator] over the hexagons at most `radius` moves away, ring after ring, starting with the Hex itself.
+    /// Example
+    /// ```rust
+    ///    use lib::hex::Hex;
+    ///
+    ///    assert_eq!(Hex::default().spiral(2).count(), 19);
+    /// ```
+    pub fn spiral(&self, radius: usize) -> impl Iterator<Item = Hex> {
+        let hex = *self;
+        (0..=radius).flat_map(move |radius| hex.ring(radius))
+    }
+}
+
+/// [Iterator] over the stored neighbours of a hexagon, see [HexMap::neighbors] and [HexMap::step].
+/// Yields hexagons and references in the order of [HexDirection::ALL].
+pub struct HexNeighbors<'a, T> {
+    map: &'a HexMap<T>,
+    hex: Hex,
+    directions: std::array::IntoIter<HexDirection, 6>,
+}
+
+impl<'a, T> Clone for HexNeighbors<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map,
+            hex: self.hex,
+            directions: self.directions.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for HexNeighbors<'a, T> {
+    type Item = (Hex, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.directions.by_ref().find_map(|dir| {
+            let neighbor = self.hex.neighbor(dir);
+            self.map.cells.get(&neighbor).map(|value| (neighbor, value))
+        })
+    }
+}
+
+/// A sparse hexagonal map, storing only the hexagons that have been inserted.
+/// It can be stepped like a cellular automaton, see [HexMap::step], or run by a [crate::grid::Automaton].
+#[derive(Debug, Clone)]
+pub struct HexMap<T> {
+    cells: HashMap<Hex, T>,
+}
+
+impl<T> Default for HexMap<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> FromIterator<(Hex, T)> for HexMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Hex, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> HexMap<T> {
+    /// Creates an empty HexMap.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieves the number of hexagons stored.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Tells if no hexagon is stored.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Retrieves a reference over the value of `hex`, if any.
+    pub fn get(&self, hex: &Hex) -> Option<&T> {
+        self.cells.get(hex)
+    }
+
+    /// Retrieves a mutable reference over the value of `hex`, if any.
+    pub fn get_mut(&mut self, hex: &Hex) -> Option<&mut T> {
+        self.cells.get_mut(hex)
+    }
+
+    /// Inserts `value` at `hex`, returning the previous value if any.
+    pub fn insert(&mut self, hex: Hex, value: T) -> Option<T> {
+        self.cells.insert(hex, value)
+    }
+
+    /// Removes the value of `hex`, returning it if any.
+    pub fn remove(&mut self, hex: &Hex) -> Option<T> {
+        self.cells.remove(hex)
+    }
+
+    /// Yields an [Iterator] of hexagons and references over every stored value, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Hex, &T)> {
+        self.cells.iter()
+    }
+
+    /// Yields an [Iterator] of hexagons and references over the stored neighbours of `hex`.
+    pub fn neighbors(&self, hex: &Hex) -> HexNeighbors<'_, T> {
+        HexNeighbors {
+            map: self,
+            hex: *hex,
+            directions: HexDirection::ALL.into_iter(),
+        }
+    }
+
+    /// Computes the next generation of the map, like [crate::grid::Grid::step]: `rule` is called on every stored
+    /// hexagon and on every empty neighbour of one, with its current value, if any, and its stored neighbours.
+    /// The hexagon is stored in the next generation only if `rule` returns a value.
+    /// Example
+    /// ```rust
+    ///    use lib::hex::{Hex, HexMap};
+    ///
+    ///    // A hexagon spreads to its neighbours, then dies.
+    ///    let map = [(Hex::default(), ())].into_iter().collect::<HexMap<_>>();
+    ///    let next = map.step(|_, value, neighbors| (value.is_none() && neighbors.count() == 1).then_some(()));
+    ///    assert_eq!(next.len(), 6);
+    ///    assert!(next.get(&Hex::default()).is_none());
+    /// ```
+    pub fn step(
+        &self,
+        rule: impl FnMut(Hex, Option<&T>, HexNeighbors<'_, T>) -> Option<T>,
+    ) -> HexMap<T> {
+        let mut next = HexMap::new();
+        self.next_cells(rule, &mut next.cells);
+        next
+    }
+
+    /// Private method computing the next generation with `rule` into `cells`, the map itself being left untouched.
+    /// `cells` is cleared first, its allocation being reused.
+    fn next_cells(
+        &self,
+        mut rule: impl FnMut(Hex, Option<&T>, HexNeighbors<'_, T>) -> Option<T>,
+        cells: &mut HashMap<Hex, T>,
+    ) {
+        let mut candidates = self.cells.keys().copied().collect::<Vec<_>>();
+        candidates.extend(
+            self.cells
+                .keys()
+                .flat_map(|hex| hex.neighbors())
+                .filter(|hex| !self.cells.contains_key(hex)),
+        );
+        candidates.sort_unstable_by_key(|hex| (hex.r, hex.q));
+        candidates.dedup();
+
+        cells.clear();
+        cells.extend(candidates.into_iter().filter_map(|hex| {
+            rule(hex, self.cells.get(&hex), self.neighbors(&hex)).map(|value| (hex, value))
+        }));
+    }
+}
+
+/// A HexMap is run by a [crate::grid::Automaton] with `()` as neighbourhood, every hexagon having six neighbours.
+/// Example
+/// ```rust
+///    use lib::{grid::Automaton, hex::{Hex, HexMap}};
+///
+///    let map = [(Hex::default(), 0)].into_iter().collect::<HexMap<_>>();
+///    let mut automaton = Automaton::new(map, ());
+///    let cycle = automaton.find_cycle(|_, value, _| value.map(|v| (v + 1) % 3));
+///    assert_eq!((cycle.start(), cycle.period()), (0, 3));
+///    assert_eq!(automaton.state().get(&Hex::default()), Some(&0));
+/// ```
+impl<T: Clone> Topology for HexMap<T> {
+    type Pos = Hex;
+    type Cell<'a>
+        = Option<&'a T>
+    where
+        T: 'a;
+    type Next = Option<T>;
+    type Neighbors<'a>
+        = HexNeighbors<'a, T>
+    where
+        T: 'a;
+    type Neighborhood = ();
+    type Snapshot = Vec<(Hex, T)>;
+
+    fn next_generation(
+        &self,
+        _: (),
+        buffer: Option<Self>,
+        rule: impl FnMut(Hex, Option<&T>, HexNeighbors<'_, T>) -> Option<T>,
+    ) -> Self {
+        let mut next = buffer.unwrap_or_default();
+        self.next_cells(rule, &mut next.cells);
+        next
+    }
+
+    fn keep(&self, hex: Hex, next: &mut Self) {
+        match self.cells.get(&hex) {
+            Some(value) => next.cells.insert(hex, value.clone()),
+            None => next.cells.remove(&hex),
+        };
+    }
+
+    fn snapshot(&self) -> Vec<(Hex, T)> {
+        let mut cells = self
+            .cells
+            .iter()
+            .map(|(hex, value)| (*hex, value.clone()))
+            .collect::<Vec<_>>();
+        cells.sort_unstable_by_key(|(hex, _)| (hex.r, hex.q));
+        cells
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LibError,
+        grid::Automaton,
+        hex::{Error, Hex, HexDirection, HexMap, HexNeighbors},
+    };
+
+    const TILES: &str = "\
+sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+
+    fn flip(_: Hex, black: Option<&()>, neighbors: HexNeighbors<'_, ()>) -> Option<()> {
+        match (black, neighbors.count()) {
+            (Some(()), 1 | 2) | (None, 2) => Some(()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn lobby_layout() -> anyhow::Result<()> {
+        let mut map = HexMap::new();
+        for line in TILES.lines() {
+            let tile = Hex::default().walk(HexDirection::parse_moves(line)?);
+            if map.remove(&tile).is_none() {
+                map.insert(tile, ());
+            }
+        }
+        assert_eq!(map.len(), 10);
+
+        assert_eq!(map.step(flip).len(), 15);
+        let mut automaton = Automaton::new(map, ());
+        automaton.run(2, flip);
+        assert_eq!(automaton.state().len(), 12);
+        automaton.run(8, flip);
+        assert_eq!(automaton.state().len(), 37);
+        assert_eq!(automaton.generation(), 10);
+
+        // A lone black tile turns white, and the empty map stays empty.
+        let lone = [(Hex::new(3, -1), ())].into_iter().collect::<HexMap<_>>();
+        let mut automaton = Automaton::new(lone, ());
+        let cycle = automaton.find_cycle(flip);
+        assert_eq!((cycle.start(), cycle.period()), (1, 1));
+        assert!(automaton.into_state().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn coordinates() -> anyhow::Result<()> {
+        let hex = Hex::from_cube(2, -3, 1)?;
+        assert_eq!(hex.cube(), (2, -3, 1));
+        assert!(matches!(
+            Hex::from_cube(1, 1, 1),
+            Err(LibError::Hex(Error::InvalidCube(1, 1, 1)))
+        ));
+        assert!(matches!(
+            "n".parse::<HexDirection>(),
+            Err(LibError::Hex(Error::InvalidDirection(_)))
+        ));
+
+        // Every neighbour is one move away, and going back returns to the start.
+        for (dir, neighbor) in HexDirection::ALL.into_iter().zip(hex.neighbors()) {
+            assert_eq!(hex.distance(&neighbor), 1);
+            assert_eq!(hex.neighbor(dir), neighbor);
+        }
+        assert_eq!(
+            hex.walk(HexDirection::parse_moves("nenwwswse")?),
+            hex.neighbor(HexDirection::East)
+                .neighbor(HexDirection::West)
+                .neighbor(HexDirection::West)
+        );
+
+        // Rings are made of distinct hexagons, each next to the previous one.
+        let ring = hex.ring(3).collect::<Vec<_>>();
+        assert_eq!(ring.len(), 18);
+        assert!(ring.iter().all(|h| h.distance(&hex) == 3));
+        assert!(ring
+            .iter()
+            .zip(ring.iter().cycle().skip(1))
+            .all(|(a, b)| a.distance(b) == 1));
+        let spiral = hex.spiral(3).collect::<std::collections::HashSet<_>>();
+        assert_eq!(spiral.len(), 37);
+
+        Ok(())
+    }
+}
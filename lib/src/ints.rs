@@ -0,0 +1,256 @@
+//! Ints module. Extracts every integer from noisy lines, or from a whole input,
+//! ignoring everything around them.
+
+use std::{marker::PhantomData, str::FromStr};
+
+use thiserror::Error;
+
+use crate::error::{LibError, LibResult};
+
+/// Error regarding integers extraction.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Raised when an integer can't be parsed to the requested type, eg. on overflow.
+    #[error("can't parse `{token}` at line {line}, column {column}: {source}")]
+    Parse {
+        /// 1-based line where the integer starts.
+        line: usize,
+        /// 1-based column where the integer starts.
+        column: usize,
+        /// The integer, as found in the input.
+        token: String,
+        /// Underlying parse error.
+        #[source]
+        source: anyhow::Error,
+    },
+    /// Raised when the number of integers doesn't match the size of the requested array.
+    #[error("{found} integers found, {expected} expected")]
+    Count {
+        /// Size of the requested array.
+        expected: usize,
+        /// Number of integers found.
+        found: usize,
+    },
+}
+
+impl From<Error> for LibError {
+    fn from(value: Error) -> Self {
+        LibError::Ints(value)
+    }
+}
+
+/// [Iterator] over the integers of a string, see [ints] and [uints].
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    input: &'a str,
+    index: usize,
+    line: usize,
+    line_start: usize,
+    signed: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> Ints<'a, T> {
+    fn new(input: &'a str, signed: bool) -> Self {
+        Self {
+            input,
+            index: 0,
+            line: 1,
+            line_start: 0,
+            signed,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Ints<'a, T>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    type Item = LibResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.input.as_bytes();
+
+        while self.index < bytes.len() && !bytes[self.index].is_ascii_digit() {
+            if bytes[self.index] == b'\n' {
+                self.line += 1;
+                self.line_start = self.index + 1;
+            }
+            self.index += 1;
+        }
+        if self.index == bytes.len() {
+            return None;
+        }
+
+        let mut start = self.index;
+        if self.signed && start > 0 && bytes[start - 1] == b'-' {
+            start -= 1;
+        }
+        while self.index < bytes.len() && bytes[self.index].is_ascii_digit() {
+            self.index += 1;
+        }
+
+        let token = &self.input[start..self.index];
+        Some(token.parse().map_err(|e: T::Err| {
+            LibError::from(Error::Parse {
+                line: self.line,
+                column: self.input[self.line_start..start].chars().count() + 1,
+                token: token.to_string(),
+                source: e.into(),
+            })
+        }))
+    }
+}
+
+/// Yields every integer of `input`, a `-` right before digits being considered as a sign.
+/// Everything that isn't part of an integer is ignored. `input` can be a line or a whole input.
+/// Example
+/// ```rust
+///    use lib::ints;
+///
+///    let numbers = ints::ints::<i32>("x=-12, y=7..-3").collect::<Result<Vec<_>, _>>().unwrap();
+///    assert_eq!(numbers, vec![-12, 7, -3]);
+/// ```
+pub fn ints<T>(input: &str) -> Ints<'_, T>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    Ints::new(input, true)
+}
+
+/// Yields every unsigned integer of `input`. A `-` is never considered as a sign, which allows
+/// to read ranges like `2-4`.
+/// Everything that isn't part of an integer is ignored. `input` can be a line or a whole input.
+/// Example
+/// ```rust
+///    use lib::ints;
+///
+///    let numbers = ints::uints::<usize>("2-4,6-8").collect::<Result<Vec<_>, _>>().unwrap();
+///    assert_eq!(numbers, vec![2, 4, 6, 8]);
+/// ```
+pub fn uints<T>(input: &str) -> Ints<'_, T>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    Ints::new(input, false)
+}
+
+/// Collects the integers of an [Ints] iterator into an array of exactly `N` elements.
+fn to_array<T, const N: usize>(ints: Ints<'_, T>) -> LibResult<[T; N]>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    let values = ints.collect::<LibResult<Vec<_>>>()?;
+    let found = values.len();
+
+    values
+        .try_into()
+        .map_err(|_| LibError::from(Error::Count { expected: N, found }))
+}
+
+/// Retrieves the `N` signed integers of `input`, see [ints]. Error is raised if there aren't exactly `N` of them.
+/// Example
+/// ```rust
+///    use lib::ints;
+///
+///    let [x, y] = ints::ints_array::<isize, 2>("<x=-1, y=2>").unwrap();
+///    assert_eq!((x, y), (-1, 2));
+/// ```
+pub fn ints_array<T, const N: usize>(input: &str) -> LibResult<[T; N]>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    to_array(ints(input))
+}
+
+/// Retrieves the `N` unsigned integers of `input`, see [uints]. Error is raised if there aren't exactly `N` of them.
+/// Example
+/// ```rust
+///    use lib::ints;
+///
+///    let [nb, from, to] = ints::uints_array::<usize, 3>("move 1 from 2 to 3").unwrap();
+///    assert_eq!((nb, from, to), (1, 2, 3));
+/// ```
+pub fn uints_array<T, const N: usize>(input: &str) -> LibResult<[T; N]>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    to_array(uints(input))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::{LibError, LibResult};
+
+    use super::{ints, ints_array, uints, uints_array, Error};
+
+    #[test]
+    fn extract() -> anyhow::Result<()> {
+        assert_eq!(
+            ints::<i64>("Sensor at x=2, y=-18: beacon at x=-2, y=15")
+                .collect::<LibResult<Vec<_>>>()?,
+            vec![2, -18, -2, 15]
+        );
+        assert_eq!(
+            uints::<u64>("Sensor at x=2, y=-18").collect::<LibResult<Vec<_>>>()?,
+            vec![2, 18]
+        );
+        assert_eq!(
+            uints::<u8>("Time:      7  15   30\nDistance:  9  40  200")
+                .collect::<LibResult<Vec<_>>>()?,
+            vec![7, 15, 30, 9, 40, 200]
+        );
+        assert!(ints::<u8>("no numbers - here").next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        let mut numbers = uints::<u8>("1 2\n3 300");
+        assert!(matches!(numbers.next(), Some(Ok(1))));
+        assert!(matches!(numbers.next(), Some(Ok(2))));
+        assert!(matches!(numbers.next(), Some(Ok(3))));
+        assert!(matches!(
+            numbers.next(),
+            Some(Err(LibError::Ints(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })))
+        ));
+        assert!(numbers.next().is_none());
+
+        // Negative integers can't be parsed as unsigned types.
+        assert!(matches!(
+            ints::<usize>("a -1").next(),
+            Some(Err(LibError::Ints(Error::Parse {
+                line: 1,
+                column: 3,
+                ..
+            })))
+        ));
+    }
+
+    #[test]
+    fn array() -> anyhow::Result<()> {
+        assert_eq!(ints_array::<i32, 3>("1,-2,3")?, [1, -2, 3]);
+        assert_eq!(uints_array::<u32, 3>("1,-2,3")?, [1, 2, 3]);
+        assert!(matches!(
+            uints_array::<u32, 2>("1,-2,3"),
+            Err(LibError::Ints(Error::Count {
+                expected: 2,
+                found: 3
+            }))
+        ));
+
+        Ok(())
+    }
+}
@@ -12,42 +12,200 @@ pub mod btree;
 pub mod direction;
 pub mod error;
 pub mod grid;
+pub mod hex;
+pub mod ints;
 pub mod nposition;
+pub mod pattern;
 pub mod permutation;
+pub mod point;
 pub mod position;
+pub mod section;
 
-use anyhow::Context;
+use error::{LibError, LibResult};
+use pattern::{FromFields, Pattern};
+use section::Section;
 
 /// Retrives a BufReader from a Path.
 /// ie. Opens the file and create a new Reader from it.
-fn get_buf(path: impl AsRef<Path>) -> BufReader<File> {
-    let file = File::open(path).expect("File not found");
-    BufReader::new(file)
+fn get_buf(path: impl AsRef<Path>) -> LibResult<BufReader<File>> {
+    let file = File::open(&path).map_err(|e| LibError::input(&path, None, e))?;
+    Ok(BufReader::new(file))
 }
 
 /// Open the file located at `path` and parse every line to `T`.
-/// `T` must be FromStr and raise `anyhow::Error` error type.
-pub fn input<T>(path: impl AsRef<Path>) -> Result<Vec<T>, anyhow::Error>
+/// `T` must be FromStr, and its error type must be convertible to `anyhow::Error`.
+/// Every I/O or parse error is reported as `LibError::Input`, along with the faulty line.
+pub fn input<T>(path: impl AsRef<Path>) -> LibResult<Vec<T>>
 where
-    T: FromStr<Err = anyhow::Error>,
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
 {
-    get_buf(path)
-        .lines()
+    input_lines(&path)?
         .enumerate()
         .map(|(index, l)| {
-            l.with_context(|| format!("Error while reading line {}", index + 1))
-                .expect("Error reading line")
-                .parse::<T>()
+            l.and_then(|l| {
+                l.parse::<T>()
+                    .map_err(|e| LibError::input(&path, Some(index + 1), e))
+            })
+        })
+        .collect()
+}
+
+/// Open the file located at `path` and parse every line to `T` using `template`, see [pattern::Pattern].
+/// Every I/O or matching error is reported as `LibError::Input`, along with the faulty line.
+/// ```rust,no_run
+///    let instructions = lib::input_pattern::<(usize, usize, usize)>("input/day5.txt", "move {} from {} to {}");
+/// ```
+pub fn input_pattern<T: FromFields>(path: impl AsRef<Path>, template: &str) -> LibResult<Vec<T>> {
+    let pattern = template.parse::<Pattern>()?;
+    input_lines(&path)?
+        .enumerate()
+        .map(|(index, l)| {
+            l.and_then(|l| {
+                pattern
+                    .parse(&l)
+                    .map_err(|e| LibError::input(&path, Some(index + 1), e))
+            })
         })
         .collect()
 }
 
 /// Open the file located at `path` and yield an iterator on every line, as String.
-pub fn input_lines(path: impl AsRef<Path>) -> impl Iterator<Item = String> {
-    get_buf(path).lines().map_while(Result::ok)
+/// An error is raised if the file can't be opened, and every line that can't be read yields a `LibError::Input`.
+pub fn input_lines(path: impl AsRef<Path>) -> LibResult<impl Iterator<Item = LibResult<String>>> {
+    let path = path.as_ref().to_path_buf();
+    Ok(get_buf(&path)?
+        .lines()
+        .enumerate()
+        .map(move |(index, l)| l.map_err(|e| LibError::input(&path, Some(index + 1), e))))
 }
 
 /// Open the file located at `path` and read everything at once, storing the whole file in a String.
-pub fn input_to_string(path: impl AsRef<Path>) -> Result<String, anyhow::Error> {
-    Ok(fs::read_to_string(path)?)
+pub fn input_to_string(path: impl AsRef<Path>) -> LibResult<String> {
+    fs::read_to_string(&path).map_err(|e| LibError::input(&path, None, e))
+}
+
+/// Open the file located at `path` and split it into [Section]s, ie. blocks of lines separated by blank lines.
+/// A section's first line ending with `:` is considered as a header, see [section::sections].
+pub fn input_sections(path: impl AsRef<Path>) -> LibResult<Vec<Section>> {
+    Ok(section::sections(&input_to_string(path)?))
+}
+
+/// Open the file located at `path`, split it into [Section]s and parse each one of them to `T`.
+/// The header of a section, if any, isn't given to `T::from_str`.
+/// Parse errors are reported as `LibError::Input`, along with the first line of the section after its header,
+/// as `T` parses the section as a whole. See [Section::body_line].
+pub fn input_sections_as<T>(path: impl AsRef<Path>) -> LibResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    input_sections(&path)?
+        .iter()
+        .map(|section| {
+            section
+                .parse()
+                .map_err(|e| LibError::input(&path, Some(section.body_line()), e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use crate::error::LibError;
+
+    /// Writes `content` in a temporary file named `name` and returns its path.
+    fn tmp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc-lib-{}-{name}", std::process::id()));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn input_missing_file() {
+        let res = crate::input::<String>("does/not/exist.txt");
+        assert!(matches!(res, Err(LibError::Input { line: None, .. })));
+        assert!(crate::input_lines("does/not/exist.txt").is_err());
+        assert!(matches!(
+            crate::input_to_string("does/not/exist.txt"),
+            Err(LibError::Input { line: None, .. })
+        ));
+    }
+
+    #[test]
+    fn input_parse_error() -> anyhow::Result<()> {
+        let path = tmp_file("input_parse_error", "1\n2\nthree\n4\n");
+        let res = crate::input::<u32>(&path);
+        fs::remove_file(&path)?;
+
+        match res {
+            Err(LibError::Input {
+                path: err_path,
+                line,
+                ..
+            }) => {
+                assert_eq!(err_path, path);
+                assert_eq!(line, Some(3));
+            }
+            other => panic!("expected LibError::Input, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn input_sections_as() -> anyhow::Result<()> {
+        let path = tmp_file("input_sections_as", "1\r\n\r\nheader:\r\n3\r\n\r\nfour\r\n");
+        let res = crate::input_sections_as::<u32>(&path);
+        let sections = crate::input_sections(&path);
+        fs::remove_file(&path)?;
+
+        assert_eq!(sections?.len(), 3);
+        assert!(matches!(res, Err(LibError::Input { line: Some(6), .. })));
+
+        // With a header, the error points to the body.
+        let path = tmp_file("input_sections_as_header", "1\n\nheader:\nfour\n");
+        let res = crate::input_sections_as::<u32>(&path);
+        fs::remove_file(&path)?;
+        assert!(matches!(res, Err(LibError::Input { line: Some(4), .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn input_pattern() -> anyhow::Result<()> {
+        let path = tmp_file("input_pattern", "a to b = 1\nb to c = 2\nc to d 3\n");
+        let res = crate::input_pattern::<(String, String, usize)>(&path, "{} to {} = {}");
+        fs::remove_file(&path)?;
+
+        match res {
+            Err(LibError::Input {
+                line: Some(3),
+                source,
+                ..
+            }) => assert!(matches!(
+                source.downcast_ref::<LibError>(),
+                Some(LibError::Pattern(crate::pattern::Error::Mismatch {
+                    column: 6,
+                    ..
+                }))
+            )),
+            other => panic!("expected LibError::Input, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn input_lines() -> anyhow::Result<()> {
+        let path = tmp_file("input_lines", "a\nb\nc\n");
+        let lines = crate::input_lines(&path)?.collect::<Result<Vec<_>, _>>();
+        fs::remove_file(&path)?;
+
+        assert_eq!(lines?, vec!["a", "b", "c"]);
+
+        Ok(())
+    }
 }
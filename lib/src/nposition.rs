@@ -1,10 +1,14 @@
 //! NPosition module. Can easily creates and interact with others types from the library.
 
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
 use thiserror::Error;
 
 use crate::{
     direction::Direction,
     error::{LibError, LibResult},
+    point::Point,
+    position::{Error as PositionError, Position},
 };
 
 /// `nposition` module inner error. Must be wrapped in LibError before being raised.
@@ -16,6 +20,9 @@ pub enum Error {
     /// Raised when `Direction` can't be applied to `NPosition`.
     #[error("can't apply {0:?} to {1:?}")]
     Apply(Direction, NPosition),
+    /// Raised when a `Position` can't be converted into a `NPosition`, a coordinate being above `isize::MAX`.
+    #[error("{0:?} doesn't fit in a NPosition")]
+    FromPosition(Position),
 }
 
 /// Easily creates LibError with the desired variant.
@@ -52,6 +59,64 @@ where
     }
 }
 
+/// Converts a Position whose coordinates are both at most `isize::MAX`.
+/// See [Position::offset_by] to convert relatively to a grid origin.
+impl TryFrom<Position> for NPosition {
+    type Error = LibError;
+
+    fn try_from(pos: Position) -> LibResult<Self> {
+        match (isize::try_from(pos.x()), isize::try_from(pos.y())) {
+            (Ok(x), Ok(y)) => Ok(Self { x, y }),
+            _ => Err(LibError::from(Error::FromPosition(pos))),
+        }
+    }
+}
+
+impl Add for NPosition {
+    type Output = NPosition;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        NPosition::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for NPosition {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for NPosition {
+    type Output = NPosition;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        NPosition::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for NPosition {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for NPosition {
+    type Output = NPosition;
+
+    fn neg(self) -> Self::Output {
+        NPosition::new(-self.x, -self.y)
+    }
+}
+
+/// Scales both coordinates.
+impl Mul<isize> for NPosition {
+    type Output = NPosition;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        NPosition::new(self.x * rhs, self.y * rhs)
+    }
+}
+
 impl NPosition {
     /// Creates a new NPosition from x - y coordinates.
     /// ```rust
@@ -85,6 +150,80 @@ impl NPosition {
         self.y
     }
 
+    /// Retrieves the Manhattan distance to `other`, ie. the number of orthogonal moves to reach it.
+    /// ```rust
+    ///    use lib::nposition::NPosition;
+    ///
+    ///    assert_eq!(NPosition::new(-1, 2).manhattan(&NPosition::new(3, -1)), 7);
+    /// ```
+    pub fn manhattan(&self, other: &NPosition) -> usize {
+        Point::from(*self).manhattan(&Point::from(*other))
+    }
+
+    /// Retrieves the Chebyshev distance to `other`, ie. the number of moves to reach it, diagonals included.
+    /// ```rust
+    ///    use lib::nposition::NPosition;
+    ///
+    ///    assert_eq!(NPosition::new(-1, 2).chebyshev(&NPosition::new(3, -1)), 4);
+    /// ```
+    pub fn chebyshev(&self, other: &NPosition) -> usize {
+        Point::from(*self).chebyshev(&Point::from(*other))
+    }
+
+    /// Retrieves the sign of both coordinates, ie. a unit step, diagonals included.
+    pub fn signum(&self) -> NPosition {
+        NPosition::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Retrieves the position one step closer to `target`, moving diagonally if both coordinates differ.
+    /// ```rust
+    ///    use lib::nposition::NPosition;
+    ///
+    ///    let pos = NPosition::new(0, 0);
+    ///    assert_eq!(pos.step_toward(&NPosition::new(2, -5)), NPosition::new(1, -1));
+    ///    assert_eq!(pos.step_toward(&NPosition::new(0, 3)), NPosition::new(0, 1));
+    ///    assert_eq!(pos.step_toward(&pos), pos);
+    /// ```
+    pub fn step_toward(&self, target: &NPosition) -> NPosition {
+        NPosition::from(Point::from(*self).step_toward(&Point::from(*target)))
+    }
+
+    /// Tells if `other` is one of the 4 orthogonal neighbours.
+    pub fn is_adjacent4(&self, other: &NPosition) -> bool {
+        self.manhattan(other) == 1
+    }
+
+    /// Tells if `other` is one of the 8 surrounding neighbours, diagonals included.
+    /// ```rust
+    ///    use lib::nposition::NPosition;
+    ///
+    ///    let pos = NPosition::new(0, 0);
+    ///    assert!(pos.is_adjacent8(&NPosition::new(-1, 1)));
+    ///    assert!(!pos.is_adjacent4(&NPosition::new(-1, 1)));
+    ///    assert!(!pos.is_adjacent8(&pos));
+    /// ```
+    pub fn is_adjacent8(&self, other: &NPosition) -> bool {
+        self.chebyshev(other) == 1
+    }
+
+    /// Retrieves the Position of the NPosition in a grid whose `(0, 0)` cell is at `origin`.
+    /// Raises an error if the NPosition is above or to the left of `origin`, or too far from it for a Position.
+    /// This is the inverse of [Position::offset_by].
+    /// ```rust
+    ///    use lib::{nposition::NPosition, position::Position};
+    ///
+    ///    let origin = NPosition::new(-2, 3);
+    ///    assert_eq!(NPosition::new(0, 3).relative_to(&origin).unwrap(), Position::new(2, 0));
+    ///    assert!(NPosition::new(0, 2).relative_to(&origin).is_err());
+    /// ```
+    pub fn relative_to(&self, origin: &NPosition) -> LibResult<Position> {
+        match (self.x.checked_sub(origin.x), self.y.checked_sub(origin.y)) {
+            (Some(x), Some(y)) => Position::try_from(NPosition::new(x, y))
+                .map_err(|_| PositionError::FromNPosition(*self).into()),
+            _ => Err(PositionError::FromNPosition(*self).into()),
+        }
+    }
+
     /// Applies a `Direction` to the `NPosition`, ie. moves to the said direction, if possible.
     /// ```rust
     ///    use lib::{nposition::NPosition, direction::Direction};
@@ -95,43 +234,12 @@ impl NPosition {
     ///    assert_eq!(pos, NPosition::new(0, -1));
     /// ```
     pub fn apply_direction(&mut self, direction: &Direction) -> LibResult<()> {
-        match direction {
-            Direction::Up => {
-                let res = self.y.checked_add(1);
-                if let Some(new_y) = res {
-                    self.y = new_y;
-                    Ok(())
-                } else {
-                    Err(Error::Apply(Direction::Up, *self).into())
-                }
-            }
-            Direction::Down => {
-                let res = self.y.checked_sub(1);
-                if let Some(new_y) = res {
-                    self.y = new_y;
-                    Ok(())
-                } else {
-                    Err(Error::Apply(Direction::Down, *self).into())
-                }
-            }
-            Direction::Left => {
-                let res = self.x.checked_sub(1);
-                if let Some(new_x) = res {
-                    self.x = new_x;
-                    Ok(())
-                } else {
-                    Err(Error::Apply(Direction::Left, *self).into())
-                }
-            }
-            Direction::Right => {
-                let res = self.x.checked_add(1);
-                if let Some(new_x) = res {
-                    self.x = new_x;
-                    Ok(())
-                } else {
-                    Err(Error::Apply(Direction::Right, *self).into())
-                }
+        match Point::from(*self).apply_direction(direction) {
+            Some(point) => {
+                *self = NPosition::from(point);
+                Ok(())
             }
+            None => Err(Error::Apply(*direction, *self).into()),
         }
     }
 }
@@ -139,7 +247,11 @@ impl NPosition {
 #[cfg(test)]
 mod test {
 
-    use crate::{direction::Direction, error::LibError};
+    use crate::{
+        direction::Direction,
+        error::LibError,
+        position::{Error as PositionError, Position},
+    };
 
     use super::{Error, NPosition};
 
@@ -150,6 +262,53 @@ mod test {
         assert!(matches!(pos, Ok(NPosition { x: -1, y: 2 })));
     }
 
+    #[test]
+    fn from_position() {
+        let origin = NPosition::new(-5, 2);
+        assert_eq!(
+            NPosition::try_from(Position::new(1, 2)).unwrap(),
+            NPosition::new(1, 2)
+        );
+        assert!(matches!(
+            NPosition::try_from(Position::new(usize::MAX, 0)),
+            Err(LibError::NPosition(Error::FromPosition(_)))
+        ));
+        assert!(matches!(
+            NPosition::new(isize::MAX, 0).relative_to(&origin),
+            Err(LibError::Position(PositionError::FromNPosition(_)))
+        ));
+        assert!(matches!(
+            NPosition::new(-5, 1).relative_to(&origin),
+            Err(LibError::Position(PositionError::FromNPosition(_)))
+        ));
+        let pos = NPosition::new(-4, 7).relative_to(&origin).unwrap();
+        assert_eq!(pos, Position::new(1, 5));
+        assert_eq!(pos.offset_by(&origin).unwrap(), NPosition::new(-4, 7));
+        assert!(matches!(
+            Position::new(isize::MAX as usize, 0).offset_by(&NPosition::new(1, 0)),
+            Err(LibError::NPosition(Error::FromPosition(_)))
+        ));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (NPosition::new(5, -2), NPosition::new(-1, 2));
+        assert_eq!(a + b, NPosition::new(4, 0));
+        assert_eq!(a - b, NPosition::new(6, -4));
+        assert_eq!(-a, NPosition::new(-5, 2));
+        assert_eq!(b * -3, NPosition::new(3, -6));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!((b - a).signum(), NPosition::new(-1, 1));
+        assert!(a.is_adjacent4(&NPosition::new(5, -1)));
+        assert!(!a.is_adjacent8(&NPosition::new(5, 0)));
+    }
+
     #[test]
     fn apply_direction() {
         // Trivial tests
@@ -0,0 +1,393 @@
+//! Pattern module. Extracts typed fields from a line using a scanf-like template,
+//! such as `"move {} from {} to {}"`.
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::error::{LibError, LibResult};
+
+/// Error regarding Pattern manipulations.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Raised when the template itself can't be used, eg. two placeholders are next to each other.
+    #[error("invalid template `{0}`")]
+    Template(String),
+    /// Raised when a literal part of the template isn't found in the line.
+    #[error("expected `{expected}` at column {column}")]
+    Mismatch {
+        /// 1-based column where the matching failed.
+        column: usize,
+        /// Literal part of the template which was expected.
+        expected: String,
+    },
+    /// Raised when the line goes on after the end of the template.
+    #[error("unexpected trailing input at column {0}")]
+    Trailing(usize),
+    /// Raised when a captured field can't be parsed to the requested type.
+    #[error("can't parse `{field}` at column {column}: {source}")]
+    Parse {
+        /// 1-based column where the field starts.
+        column: usize,
+        /// The captured field.
+        field: String,
+        /// Underlying parse error.
+        #[source]
+        source: anyhow::Error,
+    },
+    /// Raised when the number of captured fields doesn't match the requested type.
+    #[error("{found} fields captured, {expected} expected")]
+    FieldCount {
+        /// Number of fields of the requested type.
+        expected: usize,
+        /// Number of fields captured by the template.
+        found: usize,
+    },
+}
+
+impl From<Error> for LibError {
+    fn from(value: Error) -> Self {
+        LibError::Pattern(value)
+    }
+}
+
+/// A part of the template, either a literal to match or a placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Field,
+}
+
+/// A field captured from a line. Surrounding whitespaces are trimmed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field<'a> {
+    column: usize,
+    value: &'a str,
+}
+
+impl<'a> Field<'a> {
+    /// Retrieves the 1-based column where the field starts in the line.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Retrieves the captured text.
+    pub fn as_str(&self) -> &'a str {
+        self.value
+    }
+
+    /// Parses the captured text to `T`. Error is raised with the column of the field if parsing fails.
+    /// Example
+    /// ```rust
+    ///    use lib::pattern::Pattern;
+    ///
+    ///    let pattern: Pattern = "x={}".parse().unwrap();
+    ///    let fields = pattern.captures("x=12").unwrap();
+    ///    assert_eq!(fields[0].parse::<u8>().unwrap(), 12);
+    /// ```
+    pub fn parse<T>(&self) -> LibResult<T>
+    where
+        T: FromStr,
+        T::Err: Into<anyhow::Error>,
+    {
+        self.value.parse().map_err(|e: T::Err| {
+            LibError::from(Error::Parse {
+                column: self.column,
+                field: self.value.to_string(),
+                source: e.into(),
+            })
+        })
+    }
+}
+
+/// Types that can be built from the fields captured by a [Pattern].
+pub trait FromFields: Sized {
+    /// Builds `Self` from the captured fields.
+    fn from_fields(fields: &[Field<'_>]) -> LibResult<Self>;
+}
+
+/// Every field is parsed to `T`.
+impl<T, const N: usize> FromFields for [T; N]
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    fn from_fields(fields: &[Field<'_>]) -> LibResult<Self> {
+        if fields.len() != N {
+            return Err(Error::FieldCount {
+                expected: N,
+                found: fields.len(),
+            }
+            .into());
+        }
+        let values = fields
+            .iter()
+            .map(Field::parse)
+            .collect::<LibResult<Vec<T>>>()?;
+
+        values.try_into().map_err(|_| {
+            LibError::from(Error::FieldCount {
+                expected: N,
+                found: fields.len(),
+            })
+        })
+    }
+}
+
+macro_rules! impl_from_fields_for_tuple {
+    ($len:literal => $($t:ident $i:tt),+) => {
+        /// Each field is parsed to the type at the same position in the tuple.
+        impl<$($t),+> FromFields for ($($t,)+)
+        where
+            $($t: FromStr, $t::Err: Into<anyhow::Error>,)+
+        {
+            fn from_fields(fields: &[Field<'_>]) -> LibResult<Self> {
+                if fields.len() != $len {
+                    return Err(Error::FieldCount {
+                        expected: $len,
+                        found: fields.len(),
+                    }
+                    .into());
+                }
+                Ok(($(fields[$i].parse::<$t>()?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields_for_tuple!(1 => A 0);
+impl_from_fields_for_tuple!(2 => A 0, B 1);
+impl_from_fields_for_tuple!(3 => A 0, B 1, C 2);
+impl_from_fields_for_tuple!(4 => A 0, B 1, C 2, D 3);
+impl_from_fields_for_tuple!(5 => A 0, B 1, C 2, D 3, E 4);
+impl_from_fields_for_tuple!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_fields_for_tuple!(7 => A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_fields_for_tuple!(8 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// A compiled template. Every `{}` is a placeholder capturing a field, and `{{`/`}}` are literal braces.
+/// A placeholder captures everything up to the first occurrence of the following literal,
+/// or up to the end of the line if it ends the template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    parts: Vec<Part>,
+}
+
+impl FromStr for Pattern {
+    type Err = LibError;
+
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        let invalid = || LibError::from(Error::Template(template.to_string()));
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    literal.push(c);
+                }
+                ('{', Some('}')) => {
+                    chars.next();
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    } else if parts.last() == Some(&Part::Field) {
+                        // Two adjacent placeholders can't be told apart.
+                        return Err(invalid());
+                    }
+                    parts.push(Part::Field);
+                }
+                ('{', _) | ('}', _) => return Err(invalid()),
+                _ => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+
+        Ok(Self { parts })
+    }
+}
+
+impl Pattern {
+    /// Matches `line` against the template and retrieves every captured field, in order.
+    /// Error is raised with the column where matching failed.
+    /// Example
+    /// ```rust
+    ///    use lib::pattern::Pattern;
+    ///
+    ///    let pattern: Pattern = "move {} from {} to {}".parse().unwrap();
+    ///    let fields = pattern.captures("move 1 from 2 to 3").unwrap();
+    ///    assert_eq!(fields.iter().map(|f| f.as_str()).collect::<Vec<_>>(), vec!["1", "2", "3"]);
+    ///    assert!(pattern.captures("move 1 to 3").is_err());
+    /// ```
+    pub fn captures<'a>(&self, line: &'a str) -> LibResult<Vec<Field<'a>>> {
+        let column = |index: usize| line[..index].chars().count() + 1;
+        let field = |start: usize, end: usize| {
+            let raw = &line[start..end];
+            let value = raw.trim();
+            let offset = raw.len() - raw.trim_start().len();
+            Field {
+                column: column(start + offset),
+                value,
+            }
+        };
+
+        let mut fields = Vec::new();
+        let mut index = 0;
+        let mut field_start = None;
+
+        for part in &self.parts {
+            match part {
+                Part::Field => field_start = Some(index),
+                Part::Literal(lit) => {
+                    let found = match field_start.take() {
+                        Some(start) => line[index..].find(lit.as_str()).map(|i| {
+                            fields.push(field(start, index + i));
+                            index + i
+                        }),
+                        None => line[index..].starts_with(lit.as_str()).then_some(index),
+                    };
+                    match found {
+                        Some(lit_start) => index = lit_start + lit.len(),
+                        None => {
+                            return Err(Error::Mismatch {
+                                column: column(index),
+                                expected: lit.clone(),
+                            }
+                            .into())
+                        }
+                    }
+                }
+            }
+        }
+
+        match field_start {
+            Some(start) => fields.push(field(start, line.len())),
+            None if index != line.len() => return Err(Error::Trailing(column(index)).into()),
+            None => (),
+        }
+
+        Ok(fields)
+    }
+
+    /// Matches `line` against the template and parses the captured fields to `T`.
+    /// `T` is usually a tuple, each field being parsed to its own type, or an array.
+    /// Example
+    /// ```rust
+    ///    use lib::pattern::Pattern;
+    ///
+    ///    let pattern: Pattern = "{} to {} = {}".parse().unwrap();
+    ///    let (from, to, distance): (String, String, usize) = pattern.parse("London to Dublin = 464").unwrap();
+    ///    assert_eq!((from.as_str(), to.as_str(), distance), ("London", "Dublin", 464));
+    /// ```
+    pub fn parse<T: FromFields>(&self, line: &str) -> LibResult<T> {
+        T::from_fields(&self.captures(line)?)
+    }
+}
+
+/// Compiles `template` and uses it to parse `line` to `T`, see [Pattern].
+/// Example
+/// ```rust
+///    use lib::pattern;
+///
+///    let [x1, y1, x2, y2] = pattern::scan::<[usize; 4]>("{},{} through {},{}", "0,0 through 999,999").unwrap();
+///    assert_eq!((x1, y1, x2, y2), (0, 0, 999, 999));
+/// ```
+pub fn scan<T: FromFields>(template: &str, line: &str) -> LibResult<T> {
+    template.parse::<Pattern>()?.parse(line)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::LibError;
+
+    use super::{scan, Error, Pattern};
+
+    #[test]
+    fn template() {
+        assert!("move {} from {} to {}".parse::<Pattern>().is_ok());
+        assert!("{{literal}} {}".parse::<Pattern>().is_ok());
+        assert!(matches!(
+            "{}{}".parse::<Pattern>(),
+            Err(LibError::Pattern(Error::Template(_)))
+        ));
+        assert!(matches!(
+            "{ }".parse::<Pattern>(),
+            Err(LibError::Pattern(Error::Template(_)))
+        ));
+        assert!(matches!(
+            "}".parse::<Pattern>(),
+            Err(LibError::Pattern(Error::Template(_)))
+        ));
+    }
+
+    #[test]
+    fn captures() -> anyhow::Result<()> {
+        let pattern: Pattern = "Card {}: {} | {}".parse()?;
+        let fields = pattern.captures("Card   1: 41 48 | 83 86")?;
+
+        assert_eq!(
+            fields
+                .iter()
+                .map(|f| (f.column(), f.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(8, "1"), (11, "41 48"), (19, "83 86")]
+        );
+
+        let pattern: Pattern = "{{{}}}".parse()?;
+        assert_eq!(pattern.captures("{x}")?[0].as_str(), "x");
+
+        Ok(())
+    }
+
+    #[test]
+    fn mismatch() -> anyhow::Result<()> {
+        let pattern: Pattern = "move {} from {} to {}".parse()?;
+
+        assert!(matches!(
+            pattern.captures("mode 1 from 2 to 3"),
+            Err(LibError::Pattern(Error::Mismatch { column: 1, .. }))
+        ));
+        assert!(matches!(
+            pattern.captures("move 1 frm 2 to 3"),
+            Err(LibError::Pattern(Error::Mismatch { column: 6, .. }))
+        ));
+
+        let pattern: Pattern = "Game {}:".parse()?;
+        assert!(matches!(
+            pattern.captures("Game 1: 3 blue"),
+            Err(LibError::Pattern(Error::Trailing(8)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse() -> anyhow::Result<()> {
+        let (nb, from, to) =
+            scan::<(usize, u8, i32)>("move {} from {} to {}", "move 11 from 2 to 3")?;
+        assert_eq!((nb, from, to), (11, 2, 3));
+
+        assert!(matches!(
+            scan::<(usize, usize, usize)>("move {} from {} to {}", "move 1 from x to 3"),
+            Err(LibError::Pattern(Error::Parse { column: 13, .. }))
+        ));
+        assert!(matches!(
+            scan::<(usize, usize)>("move {} from {} to {}", "move 1 from 2 to 3"),
+            Err(LibError::Pattern(Error::FieldCount {
+                expected: 2,
+                found: 3
+            }))
+        ));
+        assert!(matches!(
+            scan::<[usize; 2]>("{},{},{}", "1,2,3"),
+            Err(LibError::Pattern(Error::FieldCount {
+                expected: 2,
+                found: 3
+            }))
+        ));
+
+        Ok(())
+    }
+}
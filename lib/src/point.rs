@@ -0,0 +1,203 @@
+//! Point module. A 2D point generic over its coordinate type, on which [Position] and [NPosition] rely.
+
+use std::ops::{Add, Sub};
+
+use crate::{direction::Direction, nposition::NPosition, position::Position};
+
+/// An integer type usable as the coordinate of a [Point].
+pub trait Coordinate: Copy + Ord {
+    /// The unit step.
+    const ONE: Self;
+
+    /// Adds `other`, `None` being returned on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Subtracts `other`, `None` being returned on overflow.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    /// Multiplies by `other`, `None` being returned on overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// Retrieves the absolute difference with `other`.
+    fn abs_diff(self, other: Self) -> usize;
+}
+
+/// Implements [Coordinate] for integer types whose absolute difference is a `usize`.
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn abs_diff(self, other: Self) -> usize {
+                    <$t>::abs_diff(self, other)
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(usize, isize);
+
+/// A point in a 2D environment, `x` being the horizontal coordinate and `y` the vertical one.
+/// Operations are written once here, [Position] and [NPosition] delegating to `Point<usize>` and `Point<isize>`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    x: T,
+    y: T,
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Position> for Point<usize> {
+    fn from(pos: Position) -> Self {
+        Self::new(pos.x(), pos.y())
+    }
+}
+
+impl From<Point<usize>> for Position {
+    fn from(point: Point<usize>) -> Self {
+        Position::new(point.x, point.y)
+    }
+}
+
+impl From<NPosition> for Point<isize> {
+    fn from(pos: NPosition) -> Self {
+        Self::new(pos.x(), pos.y())
+    }
+}
+
+impl From<Point<isize>> for NPosition {
+    fn from(point: Point<isize>) -> Self {
+        NPosition::new(point.x, point.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T> Point<T> {
+    /// Creates a new Point from x - y coordinates.
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    /// Retrieves the x coordinate.
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    /// Retrieves the y coordinate.
+    pub fn y(&self) -> T {
+        self.y
+    }
+
+    /// Adds `other`, `None` being returned on overflow.
+    pub fn checked_add(&self, other: &Point<T>) -> Option<Point<T>> {
+        Some(Point::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    /// Subtracts `other`, `None` being returned on overflow.
+    pub fn checked_sub(&self, other: &Point<T>) -> Option<Point<T>> {
+        Some(Point::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    /// Scales both coordinates by `factor`, `None` being returned on overflow.
+    pub fn checked_mul(&self, factor: T) -> Option<Point<T>> {
+        Some(Point::new(
+            self.x.checked_mul(factor)?,
+            self.y.checked_mul(factor)?,
+        ))
+    }
+
+    /// Retrieves the Manhattan distance to `other`, ie. the number of orthogonal moves to reach it.
+    pub fn manhattan(&self, other: &Point<T>) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Retrieves the Chebyshev distance to `other`, ie. the number of moves to reach it, diagonals included.
+    pub fn chebyshev(&self, other: &Point<T>) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Retrieves the point one step closer to `target`, moving diagonally if both coordinates differ.
+    /// Never overflows, as the step stays between the Point and `target`.
+    /// Example
+    /// ```rust
+    ///    use lib::point::Point;
+    ///
+    ///    assert_eq!(Point::new(3usize, 3).step_toward(&Point::new(0, 7)), Point::new(2, 4));
+    ///    assert_eq!(Point::new(-3isize, 3).step_toward(&Point::new(-3, -7)), Point::new(-3, 2));
+    /// ```
+    pub fn step_toward(&self, target: &Point<T>) -> Point<T> {
+        let step = |from: T, to: T| match from.cmp(&to) {
+            std::cmp::Ordering::Less => from.checked_add(T::ONE).expect("from < to"),
+            std::cmp::Ordering::Equal => from,
+            std::cmp::Ordering::Greater => from.checked_sub(T::ONE).expect("from > to"),
+        };
+        Point::new(step(self.x, target.x), step(self.y, target.y))
+    }
+
+    /// Tells if `other` is one of the 4 orthogonal neighbours.
+    pub fn is_adjacent4(&self, other: &Point<T>) -> bool {
+        self.manhattan(other) == 1
+    }
+
+    /// Tells if `other` is one of the 8 surrounding neighbours, diagonals included.
+    pub fn is_adjacent8(&self, other: &Point<T>) -> bool {
+        self.chebyshev(other) == 1
+    }
+
+    /// Retrieves the point after a move towards `direction`, [Direction::Up] increasing `y`.
+    /// `None` is returned on overflow.
+    /// Example
+    /// ```rust
+    ///    use lib::{direction::Direction, point::Point};
+    ///
+    ///    assert_eq!(Point::new(0usize, 0).apply_direction(&Direction::Up), Some(Point::new(0, 1)));
+    ///    assert_eq!(Point::new(0usize, 0).apply_direction(&Direction::Left), None);
+    ///    assert_eq!(Point::new(0isize, 0).apply_direction(&Direction::Left), Some(Point::new(-1, 0)));
+    /// ```
+    pub fn apply_direction(&self, direction: &Direction) -> Option<Point<T>> {
+        match direction {
+            Direction::Up => Some(Point::new(self.x, self.y.checked_add(T::ONE)?)),
+            Direction::Down => Some(Point::new(self.x, self.y.checked_sub(T::ONE)?)),
+            Direction::Left => Some(Point::new(self.x.checked_sub(T::ONE)?, self.y)),
+            Direction::Right => Some(Point::new(self.x.checked_add(T::ONE)?, self.y)),
+        }
+    }
+}
@@ -1,10 +1,14 @@
 //! Position module. Can easily creates and interact with others types from the library.
 
+use std::ops::{Add, Mul, Neg, Sub};
+
 use thiserror::Error;
 
 use crate::{
     direction::Direction,
     error::{LibError, LibResult},
+    nposition::{Error as NPositionError, NPosition},
+    point::Point,
 };
 
 /// `position` module inner error. Must be wrapped in LibError before being raised.
@@ -16,6 +20,9 @@ pub enum Error {
     /// Raised when `Direction`can't be applied to `Position`.
     #[error("can't apply {0:?} to {1:?}")]
     Apply(Direction, Position),
+    /// Raised when a `NPosition` with a negative coordinate is converted into a `Position`.
+    #[error("{0:?} has a negative coordinate")]
+    FromNPosition(NPosition),
 }
 
 /// Easily creates LibError with the desired variant.
@@ -51,6 +58,68 @@ where
     }
 }
 
+/// Converts a NPosition whose coordinates are both non-negative.
+/// See [NPosition::relative_to] to convert relatively to a grid origin.
+impl TryFrom<NPosition> for Position {
+    type Error = LibError;
+
+    fn try_from(pos: NPosition) -> LibResult<Self> {
+        match (usize::try_from(pos.x()), usize::try_from(pos.y())) {
+            (Ok(x), Ok(y)) => Ok(Self { x, y }),
+            _ => Err(LibError::from(Error::FromNPosition(pos))),
+        }
+    }
+}
+
+/// Checked: `None` is returned on overflow. See [Position::checked_add].
+impl Add for Position {
+    type Output = Option<Position>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs)
+    }
+}
+
+/// Checked: `None` is returned if a coordinate would become negative. See [Position::checked_sub].
+impl Sub for Position {
+    type Output = Option<Position>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs)
+    }
+}
+
+/// Checked: `None` is returned unless the Position is the origin, the only one whose opposite is a Position.
+impl Neg for Position {
+    type Output = Option<Position>;
+
+    fn neg(self) -> Self::Output {
+        (self == Position::default()).then_some(self)
+    }
+}
+
+/// Scales both coordinates by a signed factor.
+/// Checked: `None` is returned on overflow or if a coordinate would become negative.
+/// ```rust
+///    use lib::position::Position;
+///
+///    assert_eq!(Position::new(1, 2) * 3, Some(Position::new(3, 6)));
+///    assert_eq!(Position::new(0, 2) * -1, None);
+///    assert_eq!(Position::new(0, 0) * -1, Some(Position::new(0, 0)));
+/// ```
+impl Mul<isize> for Position {
+    type Output = Option<Position>;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        let scale = |coordinate: usize| match (rhs < 0, coordinate) {
+            (true, 0) => Some(0),
+            (true, _) => None,
+            (false, _) => coordinate.checked_mul(rhs.unsigned_abs()),
+        };
+        Some(Position::new(scale(self.x)?, scale(self.y)?))
+    }
+}
+
 impl Position {
     /// Creates a new Position from x - y coordinates.
     /// ```rust
@@ -84,6 +153,108 @@ impl Position {
         self.y
     }
 
+    /// Adds `other` to the Position, `None` being returned on overflow.
+    /// ```rust
+    ///    use lib::position::Position;
+    ///
+    ///    assert_eq!(Position::new(1, 2).checked_add(&Position::new(3, 4)), Some(Position::new(4, 6)));
+    ///    assert_eq!(Position::new(usize::MAX, 0).checked_add(&Position::new(1, 0)), None);
+    /// ```
+    pub fn checked_add(&self, other: &Position) -> Option<Position> {
+        Point::from(*self)
+            .checked_add(&Point::from(*other))
+            .map(Position::from)
+    }
+
+    /// Subtracts `other` from the Position, `None` being returned if a coordinate would become negative.
+    /// ```rust
+    ///    use lib::position::Position;
+    ///
+    ///    assert_eq!(Position::new(3, 4).checked_sub(&Position::new(1, 4)), Some(Position::new(2, 0)));
+    ///    assert_eq!(Position::new(3, 4).checked_sub(&Position::new(4, 1)), None);
+    /// ```
+    pub fn checked_sub(&self, other: &Position) -> Option<Position> {
+        Point::from(*self)
+            .checked_sub(&Point::from(*other))
+            .map(Position::from)
+    }
+
+    /// Scales both coordinates by `factor`, `None` being returned on overflow.
+    pub fn checked_mul(&self, factor: usize) -> Option<Position> {
+        Point::from(*self).checked_mul(factor).map(Position::from)
+    }
+
+    /// Retrieves the Manhattan distance to `other`, ie. the number of orthogonal moves to reach it.
+    /// ```rust
+    ///    use lib::position::Position;
+    ///
+    ///    assert_eq!(Position::new(1, 5).manhattan(&Position::new(4, 2)), 6);
+    /// ```
+    pub fn manhattan(&self, other: &Position) -> usize {
+        Point::from(*self).manhattan(&Point::from(*other))
+    }
+
+    /// Retrieves the Chebyshev distance to `other`, ie. the number of moves to reach it, diagonals included.
+    /// ```rust
+    ///    use lib::position::Position;
+    ///
+    ///    assert_eq!(Position::new(1, 5).chebyshev(&Position::new(4, 2)), 3);
+    /// ```
+    pub fn chebyshev(&self, other: &Position) -> usize {
+        Point::from(*self).chebyshev(&Point::from(*other))
+    }
+
+    /// Retrieves the position one step closer to `target`, moving diagonally if both coordinates differ.
+    /// Never overflows, as the step stays between the Position and `target`.
+    /// ```rust
+    ///    use lib::position::Position;
+    ///
+    ///    let pos = Position::new(3, 3);
+    ///    assert_eq!(pos.step_toward(&Position::new(0, 7)), Position::new(2, 4));
+    ///    assert_eq!(pos.step_toward(&Position::new(3, 0)), Position::new(3, 2));
+    /// ```
+    pub fn step_toward(&self, target: &Position) -> Position {
+        Position::from(Point::from(*self).step_toward(&Point::from(*target)))
+    }
+
+    /// Tells if `other` is one of the 4 orthogonal neighbours.
+    pub fn is_adjacent4(&self, other: &Position) -> bool {
+        self.manhattan(other) == 1
+    }
+
+    /// Tells if `other` is one of the 8 surrounding neighbours, diagonals included.
+    /// ```rust
+    ///    use lib::position::Position;
+    ///
+    ///    let pos = Position::new(1, 1);
+    ///    assert!(pos.is_adjacent8(&Position::new(0, 2)));
+    ///    assert!(!pos.is_adjacent4(&Position::new(0, 2)));
+    ///    assert!(!pos.is_adjacent8(&pos));
+    /// ```
+    pub fn is_adjacent8(&self, other: &Position) -> bool {
+        self.chebyshev(other) == 1
+    }
+
+    /// Retrieves the NPosition of the Position in a grid whose `(0, 0)` cell is at `origin`.
+    /// Raises an error if the result doesn't fit in a NPosition. This is the inverse of [NPosition::relative_to].
+    /// ```rust
+    ///    use lib::{nposition::NPosition, position::Position};
+    ///
+    ///    let origin = NPosition::new(-2, 3);
+    ///    assert_eq!(Position::new(1, 1).offset_by(&origin).unwrap(), NPosition::new(-1, 4));
+    ///    assert_eq!(NPosition::new(-1, 4).relative_to(&origin).unwrap(), Position::new(1, 1));
+    /// ```
+    pub fn offset_by(&self, origin: &NPosition) -> LibResult<NPosition> {
+        let pos = NPosition::try_from(*self)?;
+        match (
+            origin.x().checked_add(pos.x()),
+            origin.y().checked_add(pos.y()),
+        ) {
+            (Some(x), Some(y)) => Ok(NPosition::new(x, y)),
+            _ => Err(NPositionError::FromPosition(*self).into()),
+        }
+    }
+
     /// Applies a `Direction` to the `Position`, ie. moves to the said direction, if possible.
     /// ```rust
     ///    use lib::{position::Position, direction::Direction};
@@ -94,43 +265,12 @@ impl Position {
     ///    assert_eq!(pos, Position::new(0, 1));
     /// ```
     pub fn apply_direction(&mut self, direction: &Direction) -> LibResult<()> {
-        match direction {
-            Direction::Up => {
-                let res = self.y.checked_add(1);
-                if let Some(new_y) = res {
-                    self.y = new_y;
-                    Ok(())
-                } else {
-                    Err(Error::Apply(Direction::Up, *self).into())
-                }
-            }
-            Direction::Down => {
-                let res = self.y.checked_sub(1);
-                if let Some(new_y) = res {
-                    self.y = new_y;
-                    Ok(())
-                } else {
-                    Err(Error::Apply(Direction::Down, *self).into())
-                }
-            }
-            Direction::Left => {
-                let res = self.x.checked_sub(1);
-                if let Some(new_x) = res {
-                    self.x = new_x;
-                    Ok(())
-                } else {
-                    Err(Error::Apply(Direction::Left, *self).into())
-                }
-            }
-            Direction::Right => {
-                let res = self.x.checked_add(1);
-                if let Some(new_x) = res {
-                    self.x = new_x;
-                    Ok(())
-                } else {
-                    Err(Error::Apply(Direction::Right, *self).into())
-                }
+        match Point::from(*self).apply_direction(direction) {
+            Some(point) => {
+                *self = Position::from(point);
+                Ok(())
             }
+            None => Err(Error::Apply(*direction, *self).into()),
         }
     }
 }
@@ -138,7 +278,7 @@ impl Position {
 #[cfg(test)]
 mod test {
 
-    use crate::{direction::Direction, error::LibError};
+    use crate::{direction::Direction, error::LibError, nposition::NPosition};
 
     use super::{Error, Position};
 
@@ -154,6 +294,51 @@ mod test {
         assert!(matches!(pos4, Ok(Position { x: 1, y: 2 })));
     }
 
+    #[test]
+    fn from_nposition() {
+        assert!(matches!(
+            Position::try_from(NPosition::new(3, 4)),
+            Ok(Position { x: 3, y: 4 })
+        ));
+        assert!(matches!(
+            Position::try_from(NPosition::new(3, -4)),
+            Err(LibError::Position(Error::FromNPosition(_)))
+        ));
+        assert_eq!(
+            Position::new(3, 0)
+                .offset_by(&NPosition::new(-3, -1))
+                .unwrap(),
+            NPosition::new(0, -1)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (Position::new(5, 2), Position::new(1, 2));
+        assert_eq!(a + b, Some(Position::new(6, 4)));
+        assert_eq!(a - b, Some(Position::new(4, 0)));
+        assert_eq!(b - a, None);
+        assert_eq!(b * 3, Some(Position::new(3, 6)));
+        assert_eq!(b * -2, None);
+        assert_eq!(a * isize::MAX, None);
+        assert_eq!(-Position::default(), Some(Position::default()));
+        assert_eq!(-b, None);
+        assert_eq!(Position::new(usize::MAX, 0) + Position::new(1, 0), None);
+        assert_eq!(a.checked_mul(usize::MAX), None);
+        assert_eq!(
+            Position::new(usize::MAX, 0).checked_mul(1),
+            Some(Position::new(usize::MAX, 0))
+        );
+
+        assert_eq!(a.manhattan(&b), 4);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert!(Position::new(0, 0).is_adjacent4(&Position::new(0, 1)));
+        assert_eq!(
+            Position::new(0, usize::MAX).step_toward(&Position::new(usize::MAX, 0)),
+            Position::new(1, usize::MAX - 1)
+        );
+    }
+
     #[test]
     fn apply_direction() {
         // Trivial tests
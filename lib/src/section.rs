@@ -0,0 +1,176 @@
+//! Section module. Splits puzzle inputs made of several blocks separated by blank lines.
+
+use std::str::FromStr;
+
+/// A block of consecutive non-blank lines.
+/// If the first line of the block ends with `:` (eg. `seed-to-soil map:`), it is considered
+/// as the header of the section and isn't part of its lines.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Section {
+    header: Option<String>,
+    lines: Vec<String>,
+    line: usize,
+}
+
+impl Section {
+    /// Retrieves the header of the section, without the trailing `:`, if any.
+    /// ```rust
+    ///    use lib::section;
+    ///
+    ///    let sections = section::sections("seed-to-soil map:\n50 98 2\n");
+    ///    assert_eq!(sections[0].header(), Some("seed-to-soil map"));
+    /// ```
+    pub fn header(&self) -> Option<&str> {
+        self.header.as_deref()
+    }
+
+    /// Retrieves the lines of the section, header excluded.
+    /// ```rust
+    ///    use lib::section;
+    ///
+    ///    let sections = section::sections("seed-to-soil map:\n50 98 2\n52 50 48\n");
+    ///    assert_eq!(sections[0].lines(), &["50 98 2", "52 50 48"]);
+    /// ```
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Retrieves the 1-based line number, in the whole input, where the section starts.
+    /// ```rust
+    ///    use lib::section;
+    ///
+    ///    let sections = section::sections("1\n2\n\n3\n");
+    ///    assert_eq!(sections[1].line(), 4);
+    /// ```
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Retrieves the 1-based line number, in the whole input, of the first line after the header, if any.
+    /// ```rust
+    ///    use lib::section;
+    ///
+    ///    let sections = section::sections("1\n\nheader:\n2\n");
+    ///    assert_eq!((sections[0].body_line(), sections[1].body_line()), (1, 4));
+    /// ```
+    pub fn body_line(&self) -> usize {
+        self.line + usize::from(self.header.is_some())
+    }
+
+    /// Parses the whole section, header excluded, to `T`. Lines are joined with `\n`.
+    /// ```rust
+    ///    use lib::section;
+    ///
+    ///    let sections = section::sections("header:\nsome\ntext\n");
+    ///    assert_eq!(sections[0].parse::<String>().unwrap(), "some\ntext");
+    /// ```
+    pub fn parse<T: FromStr>(&self) -> Result<T, T::Err> {
+        self.lines.join("\n").parse()
+    }
+
+    /// Parses every line of the section, header excluded, to `T`.
+    /// ```rust
+    ///    use lib::section;
+    ///
+    ///    let sections = section::sections("1000\n2000\n\n4000\n");
+    ///    assert_eq!(sections[0].parse_lines::<u32>().unwrap(), vec![1000, 2000]);
+    ///    assert_eq!(sections[1].parse_lines::<u32>().unwrap(), vec![4000]);
+    /// ```
+    pub fn parse_lines<T: FromStr>(&self) -> Result<Vec<T>, T::Err> {
+        self.lines.iter().map(|l| l.parse()).collect()
+    }
+}
+
+/// Splits `input` into [Section]s, using blank lines as separators.
+/// Both `\n` and `\r\n` line endings are supported, and consecutive blank lines don't create empty sections.
+/// ```rust
+///    use lib::section;
+///
+///    let sections = section::sections("seeds: 79 14\r\n\r\nseed-to-soil map:\r\n50 98 2\r\n");
+///    assert_eq!(sections.len(), 2);
+///    assert_eq!(sections[0].header(), None);
+///    assert_eq!(sections[0].lines(), &["seeds: 79 14"]);
+///    assert_eq!(sections[1].header(), Some("seed-to-soil map"));
+/// ```
+pub fn sections(input: &str) -> Vec<Section> {
+    let mut sections = Vec::new();
+    let mut current: Option<Section> = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            sections.extend(current.take());
+            continue;
+        }
+
+        match current.as_mut() {
+            Some(section) => section.lines.push(line.to_string()),
+            None => {
+                current = Some(match line.strip_suffix(':') {
+                    Some(header) => Section {
+                        header: Some(header.to_string()),
+                        lines: Vec::new(),
+                        line: index + 1,
+                    },
+                    None => Section {
+                        header: None,
+                        lines: vec![line.to_string()],
+                        line: index + 1,
+                    },
+                })
+            }
+        }
+    }
+    sections.extend(current);
+
+    sections
+}
+
+#[cfg(test)]
+mod test {
+    use super::sections;
+
+    #[test]
+    fn split() {
+        let s = sections("1\n2\n\n\n3\n\nheader:\n4\n5");
+        assert_eq!(s.len(), 3);
+        assert_eq!(
+            (s[0].header(), s[0].lines(), s[0].line()),
+            (None, &["1".to_string(), "2".to_string()][..], 1)
+        );
+        assert_eq!(
+            (s[1].header(), s[1].lines(), s[1].line()),
+            (None, &["3".to_string()][..], 5)
+        );
+        assert_eq!(
+            (s[2].header(), s[2].lines(), s[2].line()),
+            (Some("header"), &["4".to_string(), "5".to_string()][..], 7)
+        );
+        assert_eq!((s[1].body_line(), s[2].body_line()), (5, 8));
+    }
+
+    #[test]
+    fn split_crlf() {
+        let s = sections("\r\n1\r\n2\r\n\r\nheader:\r\n3\r\n\r\n");
+        assert_eq!(s.len(), 2);
+        assert_eq!(s[0].lines(), &["1", "2"]);
+        assert_eq!(s[0].line(), 2);
+        assert_eq!(s[1].header(), Some("header"));
+        assert_eq!(s[1].lines(), &["3"]);
+    }
+
+    #[test]
+    fn header_only() {
+        let s = sections("empty:\n\nfull:\n1");
+        assert_eq!(s.len(), 2);
+        assert_eq!(s[0].header(), Some("empty"));
+        assert!(s[0].lines().is_empty());
+        assert_eq!(s[1].parse_lines::<u8>().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn parse_error() {
+        let s = sections("1\nnope\n");
+        assert!(s[0].parse_lines::<u8>().is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use lib::grid::Grid;
+use lib::{
+    grid::{BitGrid, Grid},
+    position::Position,
+};
 
 #[test]
 fn grid_bool_3x3() -> anyhow::Result<()> {
@@ -42,3 +45,74 @@ fn grid_bool_5x5() -> anyhow::Result<()> {
     insta::assert_display_snapshot!(g);
     Ok(())
 }
+
+#[test]
+fn grid_bool_no_color_without_tty() -> anyhow::Result<()> {
+    // Tests don't run in a TTY, so colours must be off.
+    let cells = vec![true, false, false, true];
+    let grid = Grid::try_from((cells.clone(), 2))?;
+    let bits = BitGrid::try_from((cells, 2))?;
+    assert!(!format!("{grid}").contains("\x1b["));
+    assert!(!format!("{bits}").contains("\x1b["));
+    Ok(())
+}
+
+#[test]
+fn grid_char_rulers_border() -> anyhow::Result<()> {
+    let g: Grid<char> = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\nabdefghi\nabdefghi\nabdefghi\nabdefghi\nabdefghi\nabdefghi"
+        .parse()?;
+    insta::assert_display_snapshot!(g.display().rulers(true).border(true));
+    Ok(())
+}
+
+#[test]
+fn grid_usize_separator() -> anyhow::Result<()> {
+    let g = Grid::try_from((vec![1usize, 20, 300, 4, 50, 6], 3))?;
+    insta::assert_display_snapshot!(g.display().separator(" ").rulers(true));
+    Ok(())
+}
+
+#[test]
+fn grid_u8_highlight() -> anyhow::Result<()> {
+    let g = Grid::try_from((vec![0u8; 12], 4))?;
+    let path = [(0, 0), (1, 0), (1, 1), (1, 2), (2, 2), (3, 2)]
+        .into_iter()
+        .map(|(x, y)| Position::new(x, y));
+    insta::assert_display_snapshot!(g
+        .renderer(|v| v.to_string())
+        .highlight(path)
+        .highlight_char('#')
+        .border(true));
+    Ok(())
+}
+
+#[test]
+fn grid_enum_renderer() -> anyhow::Result<()> {
+    #[derive(Debug)]
+    enum Tile {
+        Wall,
+        Open,
+        Box,
+    }
+
+    let g = Grid::try_from((
+        vec![
+            Tile::Wall,
+            Tile::Wall,
+            Tile::Wall,
+            Tile::Wall,
+            Tile::Open,
+            Tile::Wall,
+            Tile::Wall,
+            Tile::Box,
+            Tile::Wall,
+        ],
+        3,
+    ))?;
+    insta::assert_display_snapshot!(g.renderer(|tile| match tile {
+        Tile::Wall => "#".into(),
+        Tile::Open => ".".into(),
+        Tile::Box => "O".into(),
+    }));
+    Ok(())
+}